@@ -108,6 +108,30 @@ fn bench_parse_complex_all(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_parse_long_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_long");
+    // Long tab-free input: the tab/newline scan covers the whole string
+    let input = format!(
+        "https://example.com/{}?{}",
+        "segment/".repeat(256),
+        "key=value&".repeat(128)
+    );
+
+    group.bench_function("ars", |b| {
+        b.iter(|| ars::Url::parse(black_box(&input), None).unwrap());
+    });
+
+    group.bench_function("url_crate", |b| {
+        b.iter(|| UrlCrate::parse(black_box(&input)).unwrap());
+    });
+
+    group.bench_function("ada_url", |b| {
+        b.iter(|| AdaUrl::parse(black_box(&input), None).unwrap());
+    });
+
+    group.finish();
+}
+
 fn bench_parse_ipv4_all(c: &mut Criterion) {
     let mut group = c.benchmark_group("parse_ipv4");
     let input = "http://192.168.1.1:3000/api";
@@ -219,37 +243,30 @@ fn bench_relative_all(c: &mut Criterion) {
     group.finish();
 }
 
-fn bench_parse_top100urls(c: &mut Criterion) {
-    // Ensure we have the latest top100.txt before benchmarking
-    ensure_top100_txt();
-
-    // Load URLs from top100.txt
-    let urls_content = std::fs::read_to_string("./benchmarks/url-various-datasets/top100.txt")
-        .expect("Failed to read top100.txt");
-    let urls: Vec<&str> = urls_content.lines().collect();
-
-    let mut group = c.benchmark_group("parse_top100urls");
-
-    group.bench_function("ars", |b| {
+fn bench_relative_fixed_base(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_relative_fixed_base");
+    let base = "https://en.wikipedia.org/wiki/Uniform_Resource_Locator";
+    let inputs = [
+        "/wiki/URL_normalization",
+        "../w/index.php?title=URL",
+        "#History",
+        "Special:Search",
+        "//upload.wikimedia.org/a.png",
+    ];
+
+    group.bench_function("ars_string_base", |b| {
         b.iter(|| {
-            for url in &urls {
-                let _ = ars::Url::parse(black_box(url), None);
+            for input in inputs {
+                black_box(ars::Url::parse(black_box(input), Some(base)).unwrap());
             }
         });
     });
 
-    group.bench_function("url_crate", |b| {
+    group.bench_function("ars_parsed_base", |b| {
+        let parsed = ars::Url::parse(base, None).unwrap();
         b.iter(|| {
-            for url in &urls {
-                let _ = UrlCrate::parse(black_box(url));
-            }
-        });
-    });
-
-    group.bench_function("ada_url", |b| {
-        b.iter(|| {
-            for url in &urls {
-                let _ = AdaUrl::parse(black_box(url), None);
+            for input in inputs {
+                black_box(ars::Url::parse_relative(black_box(input), &parsed).unwrap());
             }
         });
     });
@@ -257,19 +274,15 @@ fn bench_parse_top100urls(c: &mut Criterion) {
     group.finish();
 }
 
-fn bench_parse_wikipedia(c: &mut Criterion) {
-    // Ensure we have the latest wikipedia_100k.txt before benchmarking
-    ensure_wikipedia_100k_txt();
-
-    // Load URLs from wikipedia_100k.txt
+/// Benchmark parsing every line of a dataset file with each library
+fn bench_dataset(c: &mut Criterion, group_name: &str, path: &str) {
     let urls_content =
-        std::fs::read_to_string("./benchmarks/url-various-datasets/wikipedia_100k.txt")
-            .expect("Failed to read wikipedia_100k.txt");
+        std::fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path}"));
     let urls: Vec<&str> = urls_content.lines().collect();
 
-    println!("📊 Benchmarking {} Wikipedia URLs", urls.len());
+    println!("📊 Benchmarking {} URLs from {}", urls.len(), path);
 
-    let mut group = c.benchmark_group("parse_wikipedia");
+    let mut group = c.benchmark_group(group_name);
 
     group.bench_function("ars", |b| {
         b.iter(|| {
@@ -279,6 +292,17 @@ fn bench_parse_wikipedia(c: &mut Criterion) {
         });
     });
 
+    group.bench_function("ars_url_parser", |b| {
+        let mut parser = ars::UrlParser::new();
+        b.iter(|| {
+            for url in &urls {
+                if let Ok(url) = parser.parse_into(black_box(url), None) {
+                    parser.recycle(url);
+                }
+            }
+        });
+    });
+
     group.bench_function("url_crate", |b| {
         b.iter(|| {
             for url in &urls {
@@ -298,6 +322,26 @@ fn bench_parse_wikipedia(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_parse_top100urls(c: &mut Criterion) {
+    // Ensure we have the latest top100.txt before benchmarking
+    ensure_top100_txt();
+    bench_dataset(
+        c,
+        "parse_top100urls",
+        "./benchmarks/url-various-datasets/top100.txt",
+    );
+}
+
+fn bench_parse_wikipedia(c: &mut Criterion) {
+    // Ensure we have the latest wikipedia_100k.txt before benchmarking
+    ensure_wikipedia_100k_txt();
+    bench_dataset(
+        c,
+        "parse_wikipedia",
+        "./benchmarks/url-various-datasets/wikipedia_100k.txt",
+    );
+}
+
 fn bench_can_parse_all(c: &mut Criterion) {
     let mut group = c.benchmark_group("can_parse");
 
@@ -326,6 +370,15 @@ fn bench_can_parse_all(c: &mut Criterion) {
         b.iter(|| ars::Url::can_parse(black_box(invalid_relative), None));
     });
 
+    // Full parse for comparison with the no-write validation path
+    group.bench_function("ars_parse_valid_simple", |b| {
+        b.iter(|| ars::Url::parse(black_box(valid_simple), None).is_ok());
+    });
+
+    group.bench_function("ars_parse_valid_complex", |b| {
+        b.iter(|| ars::Url::parse(black_box(valid_complex), None).is_ok());
+    });
+
     group.bench_function("ada_url_valid_simple", |b| {
         b.iter(|| AdaUrl::can_parse(black_box(valid_simple), None));
     });
@@ -349,10 +402,12 @@ criterion_group!(
     benches,
     bench_parse_simple_all,
     bench_parse_complex_all,
+    bench_parse_long_all,
     bench_parse_ipv4_all,
     bench_parse_ipv6_all,
     bench_getters_all,
     bench_relative_all,
+    bench_relative_fixed_base,
     bench_parse_top100urls,
     bench_parse_wikipedia,
     bench_can_parse_all
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Parsing never panics; every URL it accepts must have consistent offsets
+fuzz_target!(|input: &str| {
+    if let Ok(url) = ars::Url::parse(input, None) {
+        if let Err(message) = url.check_invariants() {
+            panic!("{message}: {input:?} -> {:?}", url.href());
+        }
+    }
+});
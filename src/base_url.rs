@@ -0,0 +1,90 @@
+use crate::error::Result;
+use crate::url_aggregator::UrlAggregator;
+
+/// A parsed base URL for resolving many relative inputs
+///
+/// The base is parsed once up front; each `resolve` call then parses only
+/// the relative input, via `Url::parse_relative`.
+///
+/// # Examples
+///
+/// ```
+/// use ars::BaseUrl;
+///
+/// let base = BaseUrl::parse("https://example.com/docs/index.html").unwrap();
+/// for (input, expected) in [
+///     ("intro.html", "https://example.com/docs/intro.html"),
+///     ("../img/a.png", "https://example.com/img/a.png"),
+/// ] {
+///     assert_eq!(base.resolve(input).unwrap().href(), expected);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseUrl(UrlAggregator);
+
+impl BaseUrl {
+    /// Parse the base URL
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid according to the WHATWG URL Standard.
+    pub fn parse(input: &str) -> Result<Self> {
+        UrlAggregator::parse(input, None).map(Self)
+    }
+
+    /// Resolve `input` against this base
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid according to the WHATWG URL Standard.
+    pub fn resolve(&self, input: &str) -> Result<UrlAggregator> {
+        UrlAggregator::parse_relative(input, &self.0)
+    }
+
+    /// Get the parsed base URL
+    pub fn url(&self) -> &UrlAggregator {
+        &self.0
+    }
+}
+
+impl From<UrlAggregator> for BaseUrl {
+    fn from(url: UrlAggregator) -> Self {
+        Self(url)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_matches_string_base() {
+        let base_str = "https://user@example.com:8080/a/b/c?q#f";
+        let base = BaseUrl::parse(base_str).unwrap();
+        for input in [
+            "d",
+            "../d",
+            "/d",
+            "//other.org/x",
+            "?x",
+            "#y",
+            "",
+            "http:rel",
+            "mailto:x@y",
+        ] {
+            let expected = UrlAggregator::parse(input, Some(base_str)).unwrap();
+            assert_eq!(base.resolve(input).unwrap(), expected, "{input}");
+        }
+        assert_eq!(base.url().href(), base_str);
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        assert!(BaseUrl::parse("not a url").is_err());
+
+        let base = BaseUrl::parse("mailto:x@y").unwrap();
+        assert!(base.resolve("z").is_err());
+        assert!(base.resolve("#frag").is_ok());
+    }
+}
@@ -40,6 +40,99 @@ pub fn parse_port(port: &str) -> Option<u16> {
     port.parse::<u16>().ok()
 }
 
+/// Check if a character is a forbidden host code point (WHATWG).
+/// These are rejected in opaque hosts of non-special URLs.
+pub const fn is_forbidden_host_code_point(ch: char) -> bool {
+    matches!(
+        ch,
+        '\0' | '\t'
+            | '\n'
+            | '\r'
+            | ' '
+            | '#'
+            | '/'
+            | ':'
+            | '<'
+            | '>'
+            | '?'
+            | '@'
+            | '['
+            | '\\'
+            | ']'
+            | '^'
+            | '|'
+    )
+}
+
+/// Check if a string is a valid host for a special or non-special URL.
+///
+/// Plain ASCII domains and IPv4 addresses of special URLs are checked in
+/// place, without allocating. Every other host (IPv6 addresses, opaque
+/// hosts, and domains that need percent-decoding or IDNA) goes through
+/// [`parse_host`](crate::parse_host), which allocates its serialization.
+///
+/// The input must not include a port.
+///
+/// # Examples
+///
+/// ```
+/// use ars::is_valid_host;
+///
+/// assert!(is_valid_host("example.com", true));
+/// assert!(is_valid_host("[::1]", true));
+/// assert!(!is_valid_host("exa mple.com", true));
+/// assert!(!is_valid_host("a^b", false));
+/// ```
+pub fn is_valid_host(host: &str, special: bool) -> bool {
+    let needs_parser = !special
+        || host.is_empty()
+        || !host.is_ascii()
+        || host.starts_with('[')
+        || host.contains('%')
+        || host
+            .as_bytes()
+            .windows(4)
+            .any(|w| w.eq_ignore_ascii_case(b"xn--"));
+    if needs_parser {
+        return crate::parser::parse_host(host, special).is_ok();
+    }
+
+    // Same checks as the parser's ASCII fast path
+    if is_ipv4(host) {
+        return crate::ipv4::parse_ipv4(host).is_ok();
+    }
+    !host
+        .bytes()
+        .any(crate::unicode::is_forbidden_domain_code_point)
+}
+
+/// Check if an IPv4 host uses a legacy notation: a hexadecimal (`0x7f`)
+/// or zero-padded octal (`0177`) part.
+pub fn is_legacy_ipv4(host: &str) -> bool {
+    let host = host.strip_suffix('.').unwrap_or(host);
+    host.split('.').any(|part| {
+        let bytes = part.as_bytes();
+        bytes.len() > 1 && bytes[0] == b'0'
+    })
+}
+
+/// Check if a string contains a '%' that is not followed by two hex digits.
+pub fn has_invalid_percent_encoding(input: &str) -> bool {
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    while let Some(pos) = crate::helpers::find_byte(b'%', &bytes[i..]) {
+        let pct = i + pos;
+        if pct + 2 >= bytes.len()
+            || !bytes[pct + 1].is_ascii_hexdigit()
+            || !bytes[pct + 2].is_ascii_hexdigit()
+        {
+            return true;
+        }
+        i = pct + 3;
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +170,69 @@ mod tests {
         assert_eq!(parse_port("abc"), None);
         assert_eq!(parse_port(""), None);
     }
+
+    #[test]
+    fn test_has_invalid_percent_encoding() {
+        assert!(!has_invalid_percent_encoding(""));
+        assert!(!has_invalid_percent_encoding("/path"));
+        assert!(!has_invalid_percent_encoding("/a%20b%2Fc"));
+        assert!(has_invalid_percent_encoding("%"));
+        assert!(has_invalid_percent_encoding("/100%"));
+        assert!(has_invalid_percent_encoding("/%2"));
+        assert!(has_invalid_percent_encoding("/%zz"));
+        assert!(has_invalid_percent_encoding("/%20%g0"));
+    }
+
+    #[test]
+    fn test_forbidden_code_points() {
+        assert!(is_forbidden_host_code_point('^'));
+        assert!(is_forbidden_host_code_point(' '));
+        assert!(!is_forbidden_host_code_point('%'));
+    }
+
+    #[test]
+    fn test_is_valid_host() {
+        // Valid domain
+        assert!(is_valid_host("example.com", true));
+        assert!(is_valid_host("Example.COM", true));
+        assert!(is_valid_host("example.com", false));
+
+        // Space is forbidden
+        assert!(!is_valid_host("exa mple.com", true));
+        assert!(!is_valid_host("exa mple.com", false));
+
+        // IPv6 literal
+        assert!(is_valid_host("[::1]", true));
+        assert!(is_valid_host("[2001:db8::1]", false));
+        assert!(!is_valid_host("[::1", true));
+        assert!(!is_valid_host("[1:2:3:4:5:6:7:8:9]", true));
+
+        // Forbidden '^'
+        assert!(!is_valid_host("a^b", true));
+        assert!(!is_valid_host("a^b", false));
+
+        // IPv4 and IPv4-like hosts
+        assert!(is_valid_host("192.168.0.1", true));
+        assert!(!is_valid_host("192.168.0.256", true));
+
+        // Percent-encoding and IDNA
+        assert!(is_valid_host("ex%61mple.com", true));
+        assert!(!is_valid_host("ex%20ample.com", true));
+        assert!(is_valid_host("日本.jp", true));
+
+        // Empty host is only valid for non-special URLs
+        assert!(!is_valid_host("", true));
+        assert!(is_valid_host("", false));
+    }
+
+    #[test]
+    fn test_is_legacy_ipv4() {
+        assert!(!is_legacy_ipv4("127.0.0.1"));
+        assert!(!is_legacy_ipv4("127.1"));
+        assert!(!is_legacy_ipv4("0.0.0.0."));
+        assert!(is_legacy_ipv4("0177.0.0.1"));
+        assert!(is_legacy_ipv4("0x7f.0.0.1"));
+        assert!(is_legacy_ipv4("127.0.0.0X1"));
+        assert!(is_legacy_ipv4("0x"));
+    }
 }
@@ -0,0 +1,197 @@
+use crate::compat::{String, Vec, format};
+use crate::unicode::percent_encode::percent_decode_bytes;
+
+/// MIME type of a `data:` URL that has none, or an invalid one
+const DEFAULT_MIME_TYPE: &str = "text/plain;charset=US-ASCII";
+
+/// Parts of a `data:` URL, produced by `Url::data_url_parts`
+///
+/// Follows the `data:` URL processor of the Fetch Standard:
+/// `data:[mediatype][;base64],payload`.
+///
+/// - `mime_type`: "text/plain;charset=US-ASCII" when missing or invalid
+/// - `is_base64`: whether the payload was base64-encoded
+/// - `data`: the decoded payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataUrl {
+    pub mime_type: String,
+    pub is_base64: bool,
+    pub data: Vec<u8>,
+}
+
+impl DataUrl {
+    /// Process the serialized URL after `data:`, without the fragment
+    ///
+    /// Returns `None` if there is no ',' or the base64 payload is invalid.
+    pub(crate) fn parse(input: &str) -> Option<Self> {
+        let (mime_type, payload) = input.split_once(',')?;
+        let mime_type = mime_type.trim_matches(is_ascii_whitespace);
+        let mut data = percent_decode_bytes(payload);
+
+        let (mime_type, is_base64) = match strip_base64_suffix(mime_type) {
+            Some(mime_type) => {
+                data = forgiving_base64_decode(&data)?;
+                (mime_type, true)
+            }
+            None => (mime_type, false),
+        };
+
+        let mime_type = if mime_type.starts_with(';') {
+            format!("text/plain{mime_type}")
+        } else {
+            String::from(mime_type)
+        };
+        let mime_type = normalize_mime_type(&mime_type).unwrap_or_else(|| DEFAULT_MIME_TYPE.into());
+
+        Some(Self {
+            mime_type,
+            is_base64,
+            data,
+        })
+    }
+}
+
+fn is_ascii_whitespace(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' ')
+}
+
+/// Strip a trailing `;base64` (spaces allowed before "base64", any case)
+fn strip_base64_suffix(mime_type: &str) -> Option<&str> {
+    let split = mime_type.len().checked_sub(6)?;
+    let (rest, suffix) = mime_type.split_at_checked(split)?;
+    if !suffix.eq_ignore_ascii_case("base64") {
+        return None;
+    }
+    rest.trim_end_matches(' ').strip_suffix(';')
+}
+
+/// Lowercase the `type/subtype` essence, keeping parameters as written
+///
+/// Returns `None` if the essence is not a valid `type/subtype` pair.
+fn normalize_mime_type(mime_type: &str) -> Option<String> {
+    let (essence, parameters) = match mime_type.find(';') {
+        Some(index) => mime_type.split_at(index),
+        None => (mime_type, ""),
+    };
+    let essence = essence.trim_matches(is_ascii_whitespace);
+    let (kind, subtype) = essence.split_once('/')?;
+    let is_token = |s: &str| {
+        !s.is_empty()
+            && s.bytes().all(|b| {
+                b.is_ascii_alphanumeric()
+                    || matches!(
+                        b,
+                        b'!' | b'#'
+                            | b'$'
+                            | b'%'
+                            | b'&'
+                            | b'\''
+                            | b'*'
+                            | b'+'
+                            | b'-'
+                            | b'.'
+                            | b'^'
+                            | b'_'
+                            | b'`'
+                            | b'|'
+                            | b'~'
+                    )
+            })
+    };
+    if !is_token(kind) || !is_token(subtype) {
+        return None;
+    }
+    let mut normalized = essence.to_ascii_lowercase();
+    normalized.push_str(parameters);
+    Some(normalized)
+}
+
+/// Forgiving-base64 decode (Infra Standard)
+///
+/// ASCII whitespace is ignored and the trailing '=' padding is optional.
+fn forgiving_base64_decode(input: &[u8]) -> Option<Vec<u8>> {
+    let mut input: Vec<u8> = input
+        .iter()
+        .copied()
+        .filter(|&b| !matches!(b, b'\t' | b'\n' | b'\x0C' | b'\r' | b' '))
+        .collect();
+    if input.len() % 4 == 0 {
+        if input.ends_with(b"==") {
+            input.truncate(input.len() - 2);
+        } else if input.ends_with(b"=") {
+            input.truncate(input.len() - 1);
+        }
+    }
+    if input.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for byte in input {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(output)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data_url() {
+        let data_url = DataUrl::parse("text/plain;base64,SGVsbG8=").unwrap();
+        assert_eq!(data_url.mime_type, "text/plain");
+        assert!(data_url.is_base64);
+        assert_eq!(data_url.data, b"Hello");
+
+        let data_url = DataUrl::parse(",hi").unwrap();
+        assert_eq!(data_url.mime_type, "text/plain;charset=US-ASCII");
+        assert!(!data_url.is_base64);
+        assert_eq!(data_url.data, b"hi");
+
+        let data_url = DataUrl::parse(" Text/HTML;charset=utf-8 ,%3Cb%3E").unwrap();
+        assert_eq!(data_url.mime_type, "text/html;charset=utf-8");
+        assert_eq!(data_url.data, b"<b>");
+
+        let data_url = DataUrl::parse(";charset=utf-8;BASE64,aGk").unwrap();
+        assert_eq!(data_url.mime_type, "text/plain;charset=utf-8");
+        assert_eq!(data_url.data, b"hi");
+
+        assert_eq!(
+            DataUrl::parse("nonsense,x").unwrap().mime_type,
+            DEFAULT_MIME_TYPE
+        );
+        assert!(DataUrl::parse("text/plain").is_none());
+        assert!(DataUrl::parse(";base64,a").is_none());
+        assert!(DataUrl::parse(";base64,a?b").is_none());
+    }
+
+    #[test]
+    fn test_forgiving_base64_decode() {
+        assert_eq!(forgiving_base64_decode(b"").unwrap(), b"");
+        assert_eq!(forgiving_base64_decode(b"YQ==").unwrap(), b"a");
+        assert_eq!(forgiving_base64_decode(b"YQ").unwrap(), b"a");
+        assert_eq!(forgiving_base64_decode(b"Y W\nJ j").unwrap(), b"abc");
+        assert_eq!(forgiving_base64_decode(b"YWJj").unwrap(), b"abc");
+        assert!(forgiving_base64_decode(b"YQ=").is_none());
+        assert!(forgiving_base64_decode(b"Y===").is_none());
+        assert!(forgiving_base64_decode(b"YWJjZ").is_none());
+    }
+}
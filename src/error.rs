@@ -21,6 +21,14 @@ pub enum ParseError {
     InvalidUrl,
     /// Relative URL without base
     RelativeUrlWithoutBase,
+    /// Input is not valid UTF-8
+    InvalidEncoding,
+    /// Input exceeds the configured maximum length
+    TooLong,
+    /// URL has a username or password, which the parse options forbid
+    ForbiddenCredentials,
+    /// URL scheme is not in the allowlist of the parse options
+    DisallowedScheme,
 }
 
 impl core::fmt::Display for ParseError {
@@ -36,6 +44,10 @@ impl core::fmt::Display for ParseError {
             Self::IdnaError => "IDNA processing error",
             Self::InvalidUrl => "Invalid URL",
             Self::RelativeUrlWithoutBase => "Relative URL without base",
+            Self::InvalidEncoding => "Invalid UTF-8 encoding",
+            Self::TooLong => "URL too long",
+            Self::ForbiddenCredentials => "URL credentials not allowed",
+            Self::DisallowedScheme => "URL scheme not allowed",
         };
         f.write_str(msg)
     }
@@ -44,5 +56,31 @@ impl core::fmt::Display for ParseError {
 #[cfg(feature = "std")]
 impl std::error::Error for ParseError {}
 
+/// Reasons a setter can reject a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetterError {
+    /// URL cannot have a username or password (no host, empty host, or `file:` scheme)
+    CannotHaveCredentials,
+    /// Protocol is not a valid scheme
+    InvalidScheme,
+    /// Protocol would switch between special and non-special schemes, or
+    /// to or from `file:`
+    IncompatibleScheme,
+}
+
+impl core::fmt::Display for SetterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::CannotHaveCredentials => "URL cannot have credentials",
+            Self::InvalidScheme => "Invalid scheme",
+            Self::IncompatibleScheme => "Scheme change not allowed",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SetterError {}
+
 /// Result type for URL parsing operations
 pub type Result<T> = core::result::Result<T, ParseError>;
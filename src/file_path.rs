@@ -0,0 +1,305 @@
+/// Conversion between `file:` URLs and filesystem paths
+/// Windows paths are handled as strings so the conversion can be tested on any platform
+use crate::compat::{String, Vec};
+use crate::error::{ParseError, Result};
+use crate::types::SchemeType;
+use crate::unicode::percent_encode::{AsciiSet, PATH_SET, percent_decode_bytes};
+use crate::url_aggregator::UrlAggregator;
+use std::path::{Path, PathBuf};
+
+/// Path percent-encode set + %, \
+/// '%' keeps names with literal percent signs from being decoded later,
+/// '\' would otherwise be read as a path separator in file URLs
+const FILE_PATH_SET: AsciiSet = PATH_SET.add(b'%').add(b'\\');
+
+impl UrlAggregator {
+    /// Convert a `file:` URL into a filesystem path
+    ///
+    /// Path segments are percent-decoded. On Windows, `/C:/x` becomes `C:\x`
+    /// and a non-empty host becomes a UNC path (`\\host\share`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the scheme is not `file:`, if the URL has a
+    /// non-empty host on platforms without UNC paths, or if a decoded
+    /// segment contains a separator or NUL byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(unix)] {
+    /// use ars::Url;
+    /// use std::path::Path;
+    ///
+    /// let url = Url::parse("file:///tmp/a%20b", None).unwrap();
+    /// assert_eq!(url.to_file_path().unwrap(), Path::new("/tmp/a b"));
+    /// # }
+    /// ```
+    // Unit error matches the `url` crate's signature
+    #[allow(clippy::result_unit_err)]
+    pub fn to_file_path(&self) -> core::result::Result<PathBuf, ()> {
+        if self.scheme_type() != SchemeType::File {
+            return Err(());
+        }
+
+        #[cfg(windows)]
+        {
+            url_to_windows_path(self.hostname(), self.pathname()).map(PathBuf::from)
+        }
+
+        #[cfg(not(windows))]
+        {
+            if !self.hostname().is_empty() {
+                return Err(());
+            }
+            let bytes = url_to_posix_path(self.pathname())?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::ffi::OsStringExt;
+                Ok(PathBuf::from(std::ffi::OsString::from_vec(bytes)))
+            }
+            #[cfg(not(unix))]
+            {
+                String::from_utf8(bytes).map(PathBuf::from).map_err(|_| ())
+            }
+        }
+    }
+
+    /// Build a `file:` URL from an absolute filesystem path
+    ///
+    /// Each path segment is percent-encoded, including '%' itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::InvalidUrl` if the path is not absolute
+    /// (or, on Windows, not valid UTF-8), or an error if the resulting
+    /// URL fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(unix)] {
+    /// use ars::Url;
+    ///
+    /// let url = Url::from_file_path("/tmp/a b").unwrap();
+    /// assert_eq!(url.href(), "file:///tmp/a%20b");
+    /// # }
+    /// ```
+    pub fn from_file_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+
+        #[cfg(windows)]
+        let href = windows_path_to_url(path.to_str().ok_or(ParseError::InvalidUrl)?)?;
+
+        #[cfg(not(windows))]
+        let href = {
+            if !path.is_absolute() {
+                return Err(ParseError::InvalidUrl);
+            }
+            #[cfg(unix)]
+            let bytes = {
+                use std::os::unix::ffi::OsStrExt;
+                path.as_os_str().as_bytes()
+            };
+            #[cfg(not(unix))]
+            let bytes = path.to_str().ok_or(ParseError::InvalidUrl)?.as_bytes();
+            posix_path_to_url(bytes)
+        };
+
+        Self::parse(&href, None)
+    }
+}
+
+/// Decode a `file:` URL pathname into POSIX path bytes
+#[cfg_attr(windows, allow(dead_code))]
+fn url_to_posix_path(pathname: &str) -> core::result::Result<Vec<u8>, ()> {
+    let mut bytes = Vec::with_capacity(pathname.len());
+    for segment in pathname.split('/').skip(1) {
+        let decoded = percent_decode_bytes(segment);
+        if decoded.iter().any(|&b| b == b'/' || b == 0) {
+            return Err(());
+        }
+        bytes.push(b'/');
+        bytes.extend_from_slice(&decoded);
+    }
+    if bytes.is_empty() {
+        bytes.push(b'/');
+    }
+    Ok(bytes)
+}
+
+/// Build a `file:` URL string from absolute POSIX path bytes
+#[cfg_attr(windows, allow(dead_code))]
+fn posix_path_to_url(path: &[u8]) -> String {
+    let mut href = String::with_capacity(path.len() + 7);
+    href.push_str("file://");
+    crate::unicode::percent_encode::percent_encode_bytes_into(&mut href, path, FILE_PATH_SET);
+    href
+}
+
+/// Decode a `file:` URL host and pathname into a Windows path
+/// `/C:/x` becomes `C:\x`, and a non-empty host becomes `\\host\...`
+#[cfg_attr(not(any(windows, test)), allow(dead_code))]
+fn url_to_windows_path(hostname: &str, pathname: &str) -> core::result::Result<String, ()> {
+    let mut segments = pathname.split('/').skip(1);
+    let mut path = String::with_capacity(pathname.len() + hostname.len() + 2);
+
+    if hostname.is_empty() {
+        // Local path: the first segment must be a drive letter
+        let drive = segments.next().ok_or(())?;
+        let bytes = drive.as_bytes();
+        if bytes.len() != 2 || !bytes[0].is_ascii_alphabetic() || bytes[1] != b':' {
+            return Err(());
+        }
+        path.push_str(drive);
+    } else {
+        path.push_str("\\\\");
+        path.push_str(hostname);
+    }
+
+    let mut has_segment = false;
+    for segment in segments {
+        let decoded = String::from_utf8(percent_decode_bytes(segment)).map_err(|_| ())?;
+        if decoded.contains(['\\', '/', '\0']) {
+            return Err(());
+        }
+        path.push('\\');
+        path.push_str(&decoded);
+        has_segment = true;
+    }
+    if !has_segment {
+        path.push('\\');
+    }
+    Ok(path)
+}
+
+/// Build a `file:` URL string from an absolute Windows path
+/// Supports drive paths (`C:\x`), UNC paths (`\\server\share`) and their
+/// verbatim forms (`\\?\C:\x`, `\\?\UNC\server\share`)
+#[cfg_attr(not(any(windows, test)), allow(dead_code))]
+fn windows_path_to_url(path: &str) -> Result<String> {
+    let is_separator = |c: char| c == '\\' || c == '/';
+
+    let (host, rest) = if let Some(unc) = path.strip_prefix(r"\\?\UNC\").or_else(|| {
+        path.strip_prefix(r"\\")
+            .filter(|p| !p.starts_with(['?', '.']))
+    }) {
+        let end = unc.find(is_separator).unwrap_or(unc.len());
+        if end == 0 {
+            return Err(ParseError::InvalidUrl);
+        }
+        (&unc[..end], &unc[end..])
+    } else {
+        let local = path.strip_prefix(r"\\?\").unwrap_or(path);
+        let bytes = local.as_bytes();
+        if bytes.len() < 3
+            || !bytes[0].is_ascii_alphabetic()
+            || bytes[1] != b':'
+            || !is_separator(bytes[2] as char)
+        {
+            return Err(ParseError::InvalidUrl);
+        }
+        ("", local)
+    };
+
+    let mut href = String::with_capacity(path.len() + 8);
+    href.push_str("file://");
+    href.push_str(host);
+    if host.is_empty() {
+        // Keep the drive letter as the first path segment
+        href.push('/');
+        href.push_str(&rest[..2]);
+    }
+    let rest = if host.is_empty() { &rest[2..] } else { rest };
+    for segment in rest.split(is_separator).skip(1) {
+        href.push('/');
+        crate::unicode::percent_encode::percent_encode_into(&mut href, segment, FILE_PATH_SET);
+    }
+    Ok(href)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_drive_path_round_trip() {
+        let href = windows_path_to_url(r"C:\Users\a b\100%.txt").unwrap();
+        assert_eq!(href, "file:///C:/Users/a%20b/100%25.txt");
+
+        let url = UrlAggregator::parse(&href, None).unwrap();
+        assert_eq!(
+            url_to_windows_path(url.hostname(), url.pathname()).unwrap(),
+            r"C:\Users\a b\100%.txt"
+        );
+
+        // Drive root and forward slashes
+        assert_eq!(windows_path_to_url("C:/").unwrap(), "file:///C:/");
+        assert_eq!(url_to_windows_path("", "/C:/").unwrap(), r"C:\");
+        assert_eq!(url_to_windows_path("", "/C:").unwrap(), r"C:\");
+    }
+
+    #[test]
+    fn test_windows_unc_path_round_trip() {
+        let href = windows_path_to_url(r"\\server\share\dir").unwrap();
+        assert_eq!(href, "file://server/share/dir");
+
+        let url = UrlAggregator::parse(&href, None).unwrap();
+        assert_eq!(
+            url_to_windows_path(url.hostname(), url.pathname()).unwrap(),
+            r"\\server\share\dir"
+        );
+
+        assert_eq!(
+            windows_path_to_url(r"\\?\UNC\server\share").unwrap(),
+            "file://server/share"
+        );
+        assert_eq!(windows_path_to_url(r"\\?\C:\x").unwrap(), "file:///C:/x");
+    }
+
+    #[test]
+    fn test_windows_path_errors() {
+        assert!(windows_path_to_url(r"relative\path").is_err());
+        assert!(windows_path_to_url("C:").is_err());
+        assert!(url_to_windows_path("", "/tmp/x").is_err());
+        assert!(url_to_windows_path("", "/C:/a%5Cb").is_err());
+    }
+
+    #[test]
+    fn test_posix_path_conversion() {
+        assert_eq!(url_to_posix_path("/tmp/a%20b").unwrap(), b"/tmp/a b");
+        assert_eq!(url_to_posix_path("/").unwrap(), b"/");
+        assert!(url_to_posix_path("/a%2Fb").is_err());
+        assert!(url_to_posix_path("/a%00b").is_err());
+        assert_eq!(posix_path_to_url(b"/tmp/a b#1"), "file:///tmp/a%20b%231");
+        assert_eq!(posix_path_to_url(b"/x\xff"), "file:///x%FF");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_to_file_path() {
+        let url = UrlAggregator::parse("file:///tmp/a%20b", None).unwrap();
+        assert_eq!(url.to_file_path().unwrap(), Path::new("/tmp/a b"));
+
+        let url = UrlAggregator::parse("file://server/share", None).unwrap();
+        assert!(url.to_file_path().is_err());
+
+        let url = UrlAggregator::parse("https://example.com/tmp", None).unwrap();
+        assert!(url.to_file_path().is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_from_file_path() {
+        let url = UrlAggregator::from_file_path("/tmp/a b").unwrap();
+        assert_eq!(url.href(), "file:///tmp/a%20b");
+        assert_eq!(url.to_file_path().unwrap(), Path::new("/tmp/a b"));
+
+        let url = UrlAggregator::from_file_path("/tmp/100%/a\\b").unwrap();
+        assert_eq!(url.href(), "file:///tmp/100%25/a%5Cb");
+        assert_eq!(url.to_file_path().unwrap(), Path::new("/tmp/100%/a\\b"));
+
+        assert!(UrlAggregator::from_file_path("relative/path").is_err());
+    }
+}
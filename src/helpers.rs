@@ -48,6 +48,7 @@ pub fn contains_byte3(a: u8, b: u8, c: u8, input: &[u8]) -> bool {
 }
 
 /// Fast check if string contains tabs or newlines
+/// Vectorized via `memchr3` when `std` is enabled
 pub fn has_tabs_or_newline(input: &str) -> bool {
     contains_byte3(b'\t', b'\n', b'\r', input.as_bytes())
 }
@@ -85,23 +86,70 @@ pub fn clean_tabs_and_newlines(input: &str) -> Cow<'_, str> {
     }
 
     // Remove internal tabs/newlines/CR from trimmed range
-    let trimmed = &input[start..end];
-    if !has_tabs_or_newline(trimmed) {
-        return Cow::Borrowed(trimmed);
+    remove_tabs_and_newlines(&input[start..end])
+}
+
+/// Remove tabs and newlines without trimming, as the setters do
+/// Returns a Cow to avoid allocation when there is nothing to remove.
+pub fn remove_tabs_and_newlines(input: &str) -> Cow<'_, str> {
+    if !has_tabs_or_newline(input) {
+        return Cow::Borrowed(input);
     }
 
     Cow::Owned(
-        trimmed
+        input
             .chars()
             .filter(|&c| !is_ascii_tab_or_newline(c))
             .collect(),
     )
 }
 
+/// Length in UTF-16 code units, as JavaScript's `String.length`
+/// ASCII input, which includes every serialized URL, is its byte length.
+pub fn utf16_len(input: &str) -> usize {
+    if input.is_ascii() {
+        return input.len();
+    }
+    input.chars().map(char::len_utf16).sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_has_tabs_or_newline() {
+        let reference = |input: &str| input.bytes().any(|b| matches!(b, b'\t' | b'\n' | b'\r'));
+        let long = "a".repeat(100);
+        let cases = [
+            "",
+            "http://example.com/",
+            "\thttp://example.com/",
+            "http://exa\nmple.com/",
+            "http://example.com/\r",
+            "\n",
+            long.as_str(),
+        ];
+        for input in cases {
+            assert_eq!(has_tabs_or_newline(input), reference(input), "{input:?}");
+        }
+
+        // Match at either end of a long input
+        let mut long_tab = long.clone();
+        long_tab.push('\t');
+        assert!(has_tabs_or_newline(&long_tab));
+        long_tab.insert(0, '\r');
+        assert!(has_tabs_or_newline(&long_tab));
+    }
+
+    #[test]
+    fn test_utf16_len() {
+        for input in ["", "abc", "é", "日本", "😀", "a😀b\u{10FFFF}"] {
+            assert_eq!(utf16_len(input), input.encode_utf16().count(), "{input:?}");
+        }
+        assert_eq!(utf16_len("😀"), 2);
+    }
+
     #[test]
     fn test_clean_tabs_and_newlines() {
         // Test trim and remove combined
@@ -1,5 +1,8 @@
+pub(crate) mod bidi_tables;
 pub(crate) mod implementation;
+pub(crate) mod mark_tables;
 pub(crate) mod norm_tables;
+pub(crate) mod script_tables;
 pub(crate) mod tables;
 
-pub(crate) use implementation::domain_to_ascii;
+pub(crate) use implementation::{domain_to_ascii, domain_to_ascii_with};
@@ -0,0 +1,768 @@
+#![allow(clippy::unreadable_literal)]
+
+// Auto-generated from the Unicode 14.0 Bidi_Class property. DO NOT EDIT.
+// Code points not listed are L (left-to-right); unassigned code points are
+// listed as L too, since IDNA disallows them anyway.
+
+pub const BIDI_L: u8 = 0;
+pub const BIDI_R: u8 = 1;
+pub const BIDI_AL: u8 = 2;
+pub const BIDI_EN: u8 = 3;
+pub const BIDI_ES: u8 = 4;
+pub const BIDI_ET: u8 = 5;
+pub const BIDI_AN: u8 = 6;
+pub const BIDI_CS: u8 = 7;
+pub const BIDI_NSM: u8 = 8;
+pub const BIDI_BN: u8 = 9;
+pub const BIDI_ON: u8 = 10;
+
+/// `(first, last, class)` ranges sorted by code point; class 11 stands for
+/// B, S, WS and the explicit embedding/isolate controls
+pub static BIDI_CLASS: [(u32, u32, u8); 746] = [
+    (0x0, 0x8, 9),
+    (0x9, 0xD, 11),
+    (0xE, 0x1B, 9),
+    (0x1C, 0x20, 11),
+    (0x21, 0x22, 10),
+    (0x23, 0x25, 5),
+    (0x26, 0x2A, 10),
+    (0x2B, 0x2B, 4),
+    (0x2C, 0x2C, 7),
+    (0x2D, 0x2D, 4),
+    (0x2E, 0x2F, 7),
+    (0x30, 0x39, 3),
+    (0x3A, 0x3A, 7),
+    (0x3B, 0x40, 10),
+    (0x5B, 0x60, 10),
+    (0x7B, 0x7E, 10),
+    (0x7F, 0x84, 9),
+    (0x85, 0x85, 11),
+    (0x86, 0x9F, 9),
+    (0xA0, 0xA0, 7),
+    (0xA1, 0xA1, 10),
+    (0xA2, 0xA5, 5),
+    (0xA6, 0xA9, 10),
+    (0xAB, 0xAC, 10),
+    (0xAD, 0xAD, 9),
+    (0xAE, 0xAF, 10),
+    (0xB0, 0xB1, 5),
+    (0xB2, 0xB3, 3),
+    (0xB4, 0xB4, 10),
+    (0xB6, 0xB8, 10),
+    (0xB9, 0xB9, 3),
+    (0xBB, 0xBF, 10),
+    (0xD7, 0xD7, 10),
+    (0xF7, 0xF7, 10),
+    (0x2B9, 0x2BA, 10),
+    (0x2C2, 0x2CF, 10),
+    (0x2D2, 0x2DF, 10),
+    (0x2E5, 0x2ED, 10),
+    (0x2EF, 0x2FF, 10),
+    (0x300, 0x36F, 8),
+    (0x374, 0x375, 10),
+    (0x37E, 0x37E, 10),
+    (0x384, 0x385, 10),
+    (0x387, 0x387, 10),
+    (0x3F6, 0x3F6, 10),
+    (0x483, 0x489, 8),
+    (0x58A, 0x58A, 10),
+    (0x58D, 0x58E, 10),
+    (0x58F, 0x58F, 5),
+    (0x591, 0x5BD, 8),
+    (0x5BE, 0x5BE, 1),
+    (0x5BF, 0x5BF, 8),
+    (0x5C0, 0x5C0, 1),
+    (0x5C1, 0x5C2, 8),
+    (0x5C3, 0x5C3, 1),
+    (0x5C4, 0x5C5, 8),
+    (0x5C6, 0x5C6, 1),
+    (0x5C7, 0x5C7, 8),
+    (0x5D0, 0x5EA, 1),
+    (0x5EF, 0x5F4, 1),
+    (0x600, 0x605, 6),
+    (0x606, 0x607, 10),
+    (0x608, 0x608, 2),
+    (0x609, 0x60A, 5),
+    (0x60B, 0x60B, 2),
+    (0x60C, 0x60C, 7),
+    (0x60D, 0x60D, 2),
+    (0x60E, 0x60F, 10),
+    (0x610, 0x61A, 8),
+    (0x61B, 0x64A, 2),
+    (0x64B, 0x65F, 8),
+    (0x660, 0x669, 6),
+    (0x66A, 0x66A, 5),
+    (0x66B, 0x66C, 6),
+    (0x66D, 0x66F, 2),
+    (0x670, 0x670, 8),
+    (0x671, 0x6D5, 2),
+    (0x6D6, 0x6DC, 8),
+    (0x6DD, 0x6DD, 6),
+    (0x6DE, 0x6DE, 10),
+    (0x6DF, 0x6E4, 8),
+    (0x6E5, 0x6E6, 2),
+    (0x6E7, 0x6E8, 8),
+    (0x6E9, 0x6E9, 10),
+    (0x6EA, 0x6ED, 8),
+    (0x6EE, 0x6EF, 2),
+    (0x6F0, 0x6F9, 3),
+    (0x6FA, 0x70D, 2),
+    (0x70F, 0x710, 2),
+    (0x711, 0x711, 8),
+    (0x712, 0x72F, 2),
+    (0x730, 0x74A, 8),
+    (0x74D, 0x7A5, 2),
+    (0x7A6, 0x7B0, 8),
+    (0x7B1, 0x7B1, 2),
+    (0x7C0, 0x7EA, 1),
+    (0x7EB, 0x7F3, 8),
+    (0x7F4, 0x7F5, 1),
+    (0x7F6, 0x7F9, 10),
+    (0x7FA, 0x7FA, 1),
+    (0x7FD, 0x7FD, 8),
+    (0x7FE, 0x815, 1),
+    (0x816, 0x819, 8),
+    (0x81A, 0x81A, 1),
+    (0x81B, 0x823, 8),
+    (0x824, 0x824, 1),
+    (0x825, 0x827, 8),
+    (0x828, 0x828, 1),
+    (0x829, 0x82D, 8),
+    (0x830, 0x83E, 1),
+    (0x840, 0x858, 1),
+    (0x859, 0x85B, 8),
+    (0x85E, 0x85E, 1),
+    (0x860, 0x86A, 2),
+    (0x870, 0x88E, 2),
+    (0x890, 0x891, 6),
+    (0x898, 0x89F, 8),
+    (0x8A0, 0x8C9, 2),
+    (0x8CA, 0x8E1, 8),
+    (0x8E2, 0x8E2, 6),
+    (0x8E3, 0x902, 8),
+    (0x93A, 0x93A, 8),
+    (0x93C, 0x93C, 8),
+    (0x941, 0x948, 8),
+    (0x94D, 0x94D, 8),
+    (0x951, 0x957, 8),
+    (0x962, 0x963, 8),
+    (0x981, 0x981, 8),
+    (0x9BC, 0x9BC, 8),
+    (0x9C1, 0x9C4, 8),
+    (0x9CD, 0x9CD, 8),
+    (0x9E2, 0x9E3, 8),
+    (0x9F2, 0x9F3, 5),
+    (0x9FB, 0x9FB, 5),
+    (0x9FE, 0x9FE, 8),
+    (0xA01, 0xA02, 8),
+    (0xA3C, 0xA3C, 8),
+    (0xA41, 0xA42, 8),
+    (0xA47, 0xA48, 8),
+    (0xA4B, 0xA4D, 8),
+    (0xA51, 0xA51, 8),
+    (0xA70, 0xA71, 8),
+    (0xA75, 0xA75, 8),
+    (0xA81, 0xA82, 8),
+    (0xABC, 0xABC, 8),
+    (0xAC1, 0xAC5, 8),
+    (0xAC7, 0xAC8, 8),
+    (0xACD, 0xACD, 8),
+    (0xAE2, 0xAE3, 8),
+    (0xAF1, 0xAF1, 5),
+    (0xAFA, 0xAFF, 8),
+    (0xB01, 0xB01, 8),
+    (0xB3C, 0xB3C, 8),
+    (0xB3F, 0xB3F, 8),
+    (0xB41, 0xB44, 8),
+    (0xB4D, 0xB4D, 8),
+    (0xB55, 0xB56, 8),
+    (0xB62, 0xB63, 8),
+    (0xB82, 0xB82, 8),
+    (0xBC0, 0xBC0, 8),
+    (0xBCD, 0xBCD, 8),
+    (0xBF3, 0xBF8, 10),
+    (0xBF9, 0xBF9, 5),
+    (0xBFA, 0xBFA, 10),
+    (0xC00, 0xC00, 8),
+    (0xC04, 0xC04, 8),
+    (0xC3C, 0xC3C, 8),
+    (0xC3E, 0xC40, 8),
+    (0xC46, 0xC48, 8),
+    (0xC4A, 0xC4D, 8),
+    (0xC55, 0xC56, 8),
+    (0xC62, 0xC63, 8),
+    (0xC78, 0xC7E, 10),
+    (0xC81, 0xC81, 8),
+    (0xCBC, 0xCBC, 8),
+    (0xCCC, 0xCCD, 8),
+    (0xCE2, 0xCE3, 8),
+    (0xD00, 0xD01, 8),
+    (0xD3B, 0xD3C, 8),
+    (0xD41, 0xD44, 8),
+    (0xD4D, 0xD4D, 8),
+    (0xD62, 0xD63, 8),
+    (0xD81, 0xD81, 8),
+    (0xDCA, 0xDCA, 8),
+    (0xDD2, 0xDD4, 8),
+    (0xDD6, 0xDD6, 8),
+    (0xE31, 0xE31, 8),
+    (0xE34, 0xE3A, 8),
+    (0xE3F, 0xE3F, 5),
+    (0xE47, 0xE4E, 8),
+    (0xEB1, 0xEB1, 8),
+    (0xEB4, 0xEBC, 8),
+    (0xEC8, 0xECD, 8),
+    (0xF18, 0xF19, 8),
+    (0xF35, 0xF35, 8),
+    (0xF37, 0xF37, 8),
+    (0xF39, 0xF39, 8),
+    (0xF3A, 0xF3D, 10),
+    (0xF71, 0xF7E, 8),
+    (0xF80, 0xF84, 8),
+    (0xF86, 0xF87, 8),
+    (0xF8D, 0xF97, 8),
+    (0xF99, 0xFBC, 8),
+    (0xFC6, 0xFC6, 8),
+    (0x102D, 0x1030, 8),
+    (0x1032, 0x1037, 8),
+    (0x1039, 0x103A, 8),
+    (0x103D, 0x103E, 8),
+    (0x1058, 0x1059, 8),
+    (0x105E, 0x1060, 8),
+    (0x1071, 0x1074, 8),
+    (0x1082, 0x1082, 8),
+    (0x1085, 0x1086, 8),
+    (0x108D, 0x108D, 8),
+    (0x109D, 0x109D, 8),
+    (0x135D, 0x135F, 8),
+    (0x1390, 0x1399, 10),
+    (0x1400, 0x1400, 10),
+    (0x1680, 0x1680, 11),
+    (0x169B, 0x169C, 10),
+    (0x1712, 0x1714, 8),
+    (0x1732, 0x1733, 8),
+    (0x1752, 0x1753, 8),
+    (0x1772, 0x1773, 8),
+    (0x17B4, 0x17B5, 8),
+    (0x17B7, 0x17BD, 8),
+    (0x17C6, 0x17C6, 8),
+    (0x17C9, 0x17D3, 8),
+    (0x17DB, 0x17DB, 5),
+    (0x17DD, 0x17DD, 8),
+    (0x17F0, 0x17F9, 10),
+    (0x1800, 0x180A, 10),
+    (0x180B, 0x180D, 8),
+    (0x180E, 0x180E, 9),
+    (0x180F, 0x180F, 8),
+    (0x1885, 0x1886, 8),
+    (0x18A9, 0x18A9, 8),
+    (0x1920, 0x1922, 8),
+    (0x1927, 0x1928, 8),
+    (0x1932, 0x1932, 8),
+    (0x1939, 0x193B, 8),
+    (0x1940, 0x1940, 10),
+    (0x1944, 0x1945, 10),
+    (0x19DE, 0x19FF, 10),
+    (0x1A17, 0x1A18, 8),
+    (0x1A1B, 0x1A1B, 8),
+    (0x1A56, 0x1A56, 8),
+    (0x1A58, 0x1A5E, 8),
+    (0x1A60, 0x1A60, 8),
+    (0x1A62, 0x1A62, 8),
+    (0x1A65, 0x1A6C, 8),
+    (0x1A73, 0x1A7C, 8),
+    (0x1A7F, 0x1A7F, 8),
+    (0x1AB0, 0x1ACE, 8),
+    (0x1B00, 0x1B03, 8),
+    (0x1B34, 0x1B34, 8),
+    (0x1B36, 0x1B3A, 8),
+    (0x1B3C, 0x1B3C, 8),
+    (0x1B42, 0x1B42, 8),
+    (0x1B6B, 0x1B73, 8),
+    (0x1B80, 0x1B81, 8),
+    (0x1BA2, 0x1BA5, 8),
+    (0x1BA8, 0x1BA9, 8),
+    (0x1BAB, 0x1BAD, 8),
+    (0x1BE6, 0x1BE6, 8),
+    (0x1BE8, 0x1BE9, 8),
+    (0x1BED, 0x1BED, 8),
+    (0x1BEF, 0x1BF1, 8),
+    (0x1C2C, 0x1C33, 8),
+    (0x1C36, 0x1C37, 8),
+    (0x1CD0, 0x1CD2, 8),
+    (0x1CD4, 0x1CE0, 8),
+    (0x1CE2, 0x1CE8, 8),
+    (0x1CED, 0x1CED, 8),
+    (0x1CF4, 0x1CF4, 8),
+    (0x1CF8, 0x1CF9, 8),
+    (0x1DC0, 0x1DFF, 8),
+    (0x1FBD, 0x1FBD, 10),
+    (0x1FBF, 0x1FC1, 10),
+    (0x1FCD, 0x1FCF, 10),
+    (0x1FDD, 0x1FDF, 10),
+    (0x1FED, 0x1FEF, 10),
+    (0x1FFD, 0x1FFE, 10),
+    (0x2000, 0x200A, 11),
+    (0x200B, 0x200D, 9),
+    (0x200F, 0x200F, 1),
+    (0x2010, 0x2027, 10),
+    (0x2028, 0x202E, 11),
+    (0x202F, 0x202F, 7),
+    (0x2030, 0x2034, 5),
+    (0x2035, 0x2043, 10),
+    (0x2044, 0x2044, 7),
+    (0x2045, 0x205E, 10),
+    (0x205F, 0x205F, 11),
+    (0x2060, 0x2064, 9),
+    (0x2066, 0x2069, 11),
+    (0x206A, 0x206F, 9),
+    (0x2070, 0x2070, 3),
+    (0x2074, 0x2079, 3),
+    (0x207A, 0x207B, 4),
+    (0x207C, 0x207E, 10),
+    (0x2080, 0x2089, 3),
+    (0x208A, 0x208B, 4),
+    (0x208C, 0x208E, 10),
+    (0x20A0, 0x20C0, 5),
+    (0x20D0, 0x20F0, 8),
+    (0x2100, 0x2101, 10),
+    (0x2103, 0x2106, 10),
+    (0x2108, 0x2109, 10),
+    (0x2114, 0x2114, 10),
+    (0x2116, 0x2118, 10),
+    (0x211E, 0x2123, 10),
+    (0x2125, 0x2125, 10),
+    (0x2127, 0x2127, 10),
+    (0x2129, 0x2129, 10),
+    (0x212E, 0x212E, 5),
+    (0x213A, 0x213B, 10),
+    (0x2140, 0x2144, 10),
+    (0x214A, 0x214D, 10),
+    (0x2150, 0x215F, 10),
+    (0x2189, 0x218B, 10),
+    (0x2190, 0x2211, 10),
+    (0x2212, 0x2212, 4),
+    (0x2213, 0x2213, 5),
+    (0x2214, 0x2335, 10),
+    (0x237B, 0x2394, 10),
+    (0x2396, 0x2426, 10),
+    (0x2440, 0x244A, 10),
+    (0x2460, 0x2487, 10),
+    (0x2488, 0x249B, 3),
+    (0x24EA, 0x26AB, 10),
+    (0x26AD, 0x27FF, 10),
+    (0x2900, 0x2B73, 10),
+    (0x2B76, 0x2B95, 10),
+    (0x2B97, 0x2BFF, 10),
+    (0x2CE5, 0x2CEA, 10),
+    (0x2CEF, 0x2CF1, 8),
+    (0x2CF9, 0x2CFF, 10),
+    (0x2D7F, 0x2D7F, 8),
+    (0x2DE0, 0x2DFF, 8),
+    (0x2E00, 0x2E5D, 10),
+    (0x2E80, 0x2E99, 10),
+    (0x2E9B, 0x2EF3, 10),
+    (0x2F00, 0x2FD5, 10),
+    (0x2FF0, 0x2FFB, 10),
+    (0x3000, 0x3000, 11),
+    (0x3001, 0x3004, 10),
+    (0x3008, 0x3020, 10),
+    (0x302A, 0x302D, 8),
+    (0x3030, 0x3030, 10),
+    (0x3036, 0x3037, 10),
+    (0x303D, 0x303F, 10),
+    (0x3099, 0x309A, 8),
+    (0x309B, 0x309C, 10),
+    (0x30A0, 0x30A0, 10),
+    (0x30FB, 0x30FB, 10),
+    (0x31C0, 0x31E3, 10),
+    (0x321D, 0x321E, 10),
+    (0x3250, 0x325F, 10),
+    (0x327C, 0x327E, 10),
+    (0x32B1, 0x32BF, 10),
+    (0x32CC, 0x32CF, 10),
+    (0x3377, 0x337A, 10),
+    (0x33DE, 0x33DF, 10),
+    (0x33FF, 0x33FF, 10),
+    (0x4DC0, 0x4DFF, 10),
+    (0xA490, 0xA4C6, 10),
+    (0xA60D, 0xA60F, 10),
+    (0xA66F, 0xA672, 8),
+    (0xA673, 0xA673, 10),
+    (0xA674, 0xA67D, 8),
+    (0xA67E, 0xA67F, 10),
+    (0xA69E, 0xA69F, 8),
+    (0xA6F0, 0xA6F1, 8),
+    (0xA700, 0xA721, 10),
+    (0xA788, 0xA788, 10),
+    (0xA802, 0xA802, 8),
+    (0xA806, 0xA806, 8),
+    (0xA80B, 0xA80B, 8),
+    (0xA825, 0xA826, 8),
+    (0xA828, 0xA82B, 10),
+    (0xA82C, 0xA82C, 8),
+    (0xA838, 0xA839, 5),
+    (0xA874, 0xA877, 10),
+    (0xA8C4, 0xA8C5, 8),
+    (0xA8E0, 0xA8F1, 8),
+    (0xA8FF, 0xA8FF, 8),
+    (0xA926, 0xA92D, 8),
+    (0xA947, 0xA951, 8),
+    (0xA980, 0xA982, 8),
+    (0xA9B3, 0xA9B3, 8),
+    (0xA9B6, 0xA9B9, 8),
+    (0xA9BC, 0xA9BD, 8),
+    (0xA9E5, 0xA9E5, 8),
+    (0xAA29, 0xAA2E, 8),
+    (0xAA31, 0xAA32, 8),
+    (0xAA35, 0xAA36, 8),
+    (0xAA43, 0xAA43, 8),
+    (0xAA4C, 0xAA4C, 8),
+    (0xAA7C, 0xAA7C, 8),
+    (0xAAB0, 0xAAB0, 8),
+    (0xAAB2, 0xAAB4, 8),
+    (0xAAB7, 0xAAB8, 8),
+    (0xAABE, 0xAABF, 8),
+    (0xAAC1, 0xAAC1, 8),
+    (0xAAEC, 0xAAED, 8),
+    (0xAAF6, 0xAAF6, 8),
+    (0xAB6A, 0xAB6B, 10),
+    (0xABE5, 0xABE5, 8),
+    (0xABE8, 0xABE8, 8),
+    (0xABED, 0xABED, 8),
+    (0xFB1D, 0xFB1D, 1),
+    (0xFB1E, 0xFB1E, 8),
+    (0xFB1F, 0xFB28, 1),
+    (0xFB29, 0xFB29, 4),
+    (0xFB2A, 0xFB36, 1),
+    (0xFB38, 0xFB3C, 1),
+    (0xFB3E, 0xFB3E, 1),
+    (0xFB40, 0xFB41, 1),
+    (0xFB43, 0xFB44, 1),
+    (0xFB46, 0xFB4F, 1),
+    (0xFB50, 0xFBC2, 2),
+    (0xFBD3, 0xFD3D, 2),
+    (0xFD3E, 0xFD4F, 10),
+    (0xFD50, 0xFD8F, 2),
+    (0xFD92, 0xFDC7, 2),
+    (0xFDCF, 0xFDCF, 10),
+    (0xFDF0, 0xFDFC, 2),
+    (0xFDFD, 0xFDFF, 10),
+    (0xFE00, 0xFE0F, 8),
+    (0xFE10, 0xFE19, 10),
+    (0xFE20, 0xFE2F, 8),
+    (0xFE30, 0xFE4F, 10),
+    (0xFE50, 0xFE50, 7),
+    (0xFE51, 0xFE51, 10),
+    (0xFE52, 0xFE52, 7),
+    (0xFE54, 0xFE54, 10),
+    (0xFE55, 0xFE55, 7),
+    (0xFE56, 0xFE5E, 10),
+    (0xFE5F, 0xFE5F, 5),
+    (0xFE60, 0xFE61, 10),
+    (0xFE62, 0xFE63, 4),
+    (0xFE64, 0xFE66, 10),
+    (0xFE68, 0xFE68, 10),
+    (0xFE69, 0xFE6A, 5),
+    (0xFE6B, 0xFE6B, 10),
+    (0xFE70, 0xFE74, 2),
+    (0xFE76, 0xFEFC, 2),
+    (0xFEFF, 0xFEFF, 9),
+    (0xFF01, 0xFF02, 10),
+    (0xFF03, 0xFF05, 5),
+    (0xFF06, 0xFF0A, 10),
+    (0xFF0B, 0xFF0B, 4),
+    (0xFF0C, 0xFF0C, 7),
+    (0xFF0D, 0xFF0D, 4),
+    (0xFF0E, 0xFF0F, 7),
+    (0xFF10, 0xFF19, 3),
+    (0xFF1A, 0xFF1A, 7),
+    (0xFF1B, 0xFF20, 10),
+    (0xFF3B, 0xFF40, 10),
+    (0xFF5B, 0xFF65, 10),
+    (0xFFE0, 0xFFE1, 5),
+    (0xFFE2, 0xFFE4, 10),
+    (0xFFE5, 0xFFE6, 5),
+    (0xFFE8, 0xFFEE, 10),
+    (0xFFF9, 0xFFFD, 10),
+    (0x10101, 0x10101, 10),
+    (0x10140, 0x1018C, 10),
+    (0x10190, 0x1019C, 10),
+    (0x101A0, 0x101A0, 10),
+    (0x101FD, 0x101FD, 8),
+    (0x102E0, 0x102E0, 8),
+    (0x102E1, 0x102FB, 3),
+    (0x10376, 0x1037A, 8),
+    (0x10800, 0x10805, 1),
+    (0x10808, 0x10808, 1),
+    (0x1080A, 0x10835, 1),
+    (0x10837, 0x10838, 1),
+    (0x1083C, 0x1083C, 1),
+    (0x1083F, 0x10855, 1),
+    (0x10857, 0x1089E, 1),
+    (0x108A7, 0x108AF, 1),
+    (0x108E0, 0x108F2, 1),
+    (0x108F4, 0x108F5, 1),
+    (0x108FB, 0x1091B, 1),
+    (0x1091F, 0x1091F, 10),
+    (0x10920, 0x10939, 1),
+    (0x1093F, 0x1093F, 1),
+    (0x10980, 0x109B7, 1),
+    (0x109BC, 0x109CF, 1),
+    (0x109D2, 0x10A00, 1),
+    (0x10A01, 0x10A03, 8),
+    (0x10A05, 0x10A06, 8),
+    (0x10A0C, 0x10A0F, 8),
+    (0x10A10, 0x10A13, 1),
+    (0x10A15, 0x10A17, 1),
+    (0x10A19, 0x10A35, 1),
+    (0x10A38, 0x10A3A, 8),
+    (0x10A3F, 0x10A3F, 8),
+    (0x10A40, 0x10A48, 1),
+    (0x10A50, 0x10A58, 1),
+    (0x10A60, 0x10A9F, 1),
+    (0x10AC0, 0x10AE4, 1),
+    (0x10AE5, 0x10AE6, 8),
+    (0x10AEB, 0x10AF6, 1),
+    (0x10B00, 0x10B35, 1),
+    (0x10B39, 0x10B3F, 10),
+    (0x10B40, 0x10B55, 1),
+    (0x10B58, 0x10B72, 1),
+    (0x10B78, 0x10B91, 1),
+    (0x10B99, 0x10B9C, 1),
+    (0x10BA9, 0x10BAF, 1),
+    (0x10C00, 0x10C48, 1),
+    (0x10C80, 0x10CB2, 1),
+    (0x10CC0, 0x10CF2, 1),
+    (0x10CFA, 0x10CFF, 1),
+    (0x10D00, 0x10D23, 2),
+    (0x10D24, 0x10D27, 8),
+    (0x10D30, 0x10D39, 6),
+    (0x10E60, 0x10E7E, 6),
+    (0x10E80, 0x10EA9, 1),
+    (0x10EAB, 0x10EAC, 8),
+    (0x10EAD, 0x10EAD, 1),
+    (0x10EB0, 0x10EB1, 1),
+    (0x10F00, 0x10F27, 1),
+    (0x10F30, 0x10F45, 2),
+    (0x10F46, 0x10F50, 8),
+    (0x10F51, 0x10F59, 2),
+    (0x10F70, 0x10F81, 1),
+    (0x10F82, 0x10F85, 8),
+    (0x10F86, 0x10F89, 1),
+    (0x10FB0, 0x10FCB, 1),
+    (0x10FE0, 0x10FF6, 1),
+    (0x11001, 0x11001, 8),
+    (0x11038, 0x11046, 8),
+    (0x11052, 0x11065, 10),
+    (0x11070, 0x11070, 8),
+    (0x11073, 0x11074, 8),
+    (0x1107F, 0x11081, 8),
+    (0x110B3, 0x110B6, 8),
+    (0x110B9, 0x110BA, 8),
+    (0x110C2, 0x110C2, 8),
+    (0x11100, 0x11102, 8),
+    (0x11127, 0x1112B, 8),
+    (0x1112D, 0x11134, 8),
+    (0x11173, 0x11173, 8),
+    (0x11180, 0x11181, 8),
+    (0x111B6, 0x111BE, 8),
+    (0x111C9, 0x111CC, 8),
+    (0x111CF, 0x111CF, 8),
+    (0x1122F, 0x11231, 8),
+    (0x11234, 0x11234, 8),
+    (0x11236, 0x11237, 8),
+    (0x1123E, 0x1123E, 8),
+    (0x112DF, 0x112DF, 8),
+    (0x112E3, 0x112EA, 8),
+    (0x11300, 0x11301, 8),
+    (0x1133B, 0x1133C, 8),
+    (0x11340, 0x11340, 8),
+    (0x11366, 0x1136C, 8),
+    (0x11370, 0x11374, 8),
+    (0x11438, 0x1143F, 8),
+    (0x11442, 0x11444, 8),
+    (0x11446, 0x11446, 8),
+    (0x1145E, 0x1145E, 8),
+    (0x114B3, 0x114B8, 8),
+    (0x114BA, 0x114BA, 8),
+    (0x114BF, 0x114C0, 8),
+    (0x114C2, 0x114C3, 8),
+    (0x115B2, 0x115B5, 8),
+    (0x115BC, 0x115BD, 8),
+    (0x115BF, 0x115C0, 8),
+    (0x115DC, 0x115DD, 8),
+    (0x11633, 0x1163A, 8),
+    (0x1163D, 0x1163D, 8),
+    (0x1163F, 0x11640, 8),
+    (0x11660, 0x1166C, 10),
+    (0x116AB, 0x116AB, 8),
+    (0x116AD, 0x116AD, 8),
+    (0x116B0, 0x116B5, 8),
+    (0x116B7, 0x116B7, 8),
+    (0x1171D, 0x1171F, 8),
+    (0x11722, 0x11725, 8),
+    (0x11727, 0x1172B, 8),
+    (0x1182F, 0x11837, 8),
+    (0x11839, 0x1183A, 8),
+    (0x1193B, 0x1193C, 8),
+    (0x1193E, 0x1193E, 8),
+    (0x11943, 0x11943, 8),
+    (0x119D4, 0x119D7, 8),
+    (0x119DA, 0x119DB, 8),
+    (0x119E0, 0x119E0, 8),
+    (0x11A01, 0x11A06, 8),
+    (0x11A09, 0x11A0A, 8),
+    (0x11A33, 0x11A38, 8),
+    (0x11A3B, 0x11A3E, 8),
+    (0x11A47, 0x11A47, 8),
+    (0x11A51, 0x11A56, 8),
+    (0x11A59, 0x11A5B, 8),
+    (0x11A8A, 0x11A96, 8),
+    (0x11A98, 0x11A99, 8),
+    (0x11C30, 0x11C36, 8),
+    (0x11C38, 0x11C3D, 8),
+    (0x11C92, 0x11CA7, 8),
+    (0x11CAA, 0x11CB0, 8),
+    (0x11CB2, 0x11CB3, 8),
+    (0x11CB5, 0x11CB6, 8),
+    (0x11D31, 0x11D36, 8),
+    (0x11D3A, 0x11D3A, 8),
+    (0x11D3C, 0x11D3D, 8),
+    (0x11D3F, 0x11D45, 8),
+    (0x11D47, 0x11D47, 8),
+    (0x11D90, 0x11D91, 8),
+    (0x11D95, 0x11D95, 8),
+    (0x11D97, 0x11D97, 8),
+    (0x11EF3, 0x11EF4, 8),
+    (0x11FD5, 0x11FDC, 10),
+    (0x11FDD, 0x11FE0, 5),
+    (0x11FE1, 0x11FF1, 10),
+    (0x16AF0, 0x16AF4, 8),
+    (0x16B30, 0x16B36, 8),
+    (0x16F4F, 0x16F4F, 8),
+    (0x16F8F, 0x16F92, 8),
+    (0x16FE2, 0x16FE2, 10),
+    (0x16FE4, 0x16FE4, 8),
+    (0x1BC9D, 0x1BC9E, 8),
+    (0x1BCA0, 0x1BCA3, 9),
+    (0x1CF00, 0x1CF2D, 8),
+    (0x1CF30, 0x1CF46, 8),
+    (0x1D167, 0x1D169, 8),
+    (0x1D173, 0x1D17A, 9),
+    (0x1D17B, 0x1D182, 8),
+    (0x1D185, 0x1D18B, 8),
+    (0x1D1AA, 0x1D1AD, 8),
+    (0x1D1E9, 0x1D1EA, 10),
+    (0x1D200, 0x1D241, 10),
+    (0x1D242, 0x1D244, 8),
+    (0x1D245, 0x1D245, 10),
+    (0x1D300, 0x1D356, 10),
+    (0x1D6DB, 0x1D6DB, 10),
+    (0x1D715, 0x1D715, 10),
+    (0x1D74F, 0x1D74F, 10),
+    (0x1D789, 0x1D789, 10),
+    (0x1D7C3, 0x1D7C3, 10),
+    (0x1D7CE, 0x1D7FF, 3),
+    (0x1DA00, 0x1DA36, 8),
+    (0x1DA3B, 0x1DA6C, 8),
+    (0x1DA75, 0x1DA75, 8),
+    (0x1DA84, 0x1DA84, 8),
+    (0x1DA9B, 0x1DA9F, 8),
+    (0x1DAA1, 0x1DAAF, 8),
+    (0x1E000, 0x1E006, 8),
+    (0x1E008, 0x1E018, 8),
+    (0x1E01B, 0x1E021, 8),
+    (0x1E023, 0x1E024, 8),
+    (0x1E026, 0x1E02A, 8),
+    (0x1E130, 0x1E136, 8),
+    (0x1E2AE, 0x1E2AE, 8),
+    (0x1E2EC, 0x1E2EF, 8),
+    (0x1E2FF, 0x1E2FF, 5),
+    (0x1E800, 0x1E8C4, 1),
+    (0x1E8C7, 0x1E8CF, 1),
+    (0x1E8D0, 0x1E8D6, 8),
+    (0x1E900, 0x1E943, 1),
+    (0x1E944, 0x1E94A, 8),
+    (0x1E94B, 0x1E94B, 1),
+    (0x1E950, 0x1E959, 1),
+    (0x1E95E, 0x1E95F, 1),
+    (0x1EC71, 0x1ECB4, 2),
+    (0x1ED01, 0x1ED3D, 2),
+    (0x1EE00, 0x1EE03, 2),
+    (0x1EE05, 0x1EE1F, 2),
+    (0x1EE21, 0x1EE22, 2),
+    (0x1EE24, 0x1EE24, 2),
+    (0x1EE27, 0x1EE27, 2),
+    (0x1EE29, 0x1EE32, 2),
+    (0x1EE34, 0x1EE37, 2),
+    (0x1EE39, 0x1EE39, 2),
+    (0x1EE3B, 0x1EE3B, 2),
+    (0x1EE42, 0x1EE42, 2),
+    (0x1EE47, 0x1EE47, 2),
+    (0x1EE49, 0x1EE49, 2),
+    (0x1EE4B, 0x1EE4B, 2),
+    (0x1EE4D, 0x1EE4F, 2),
+    (0x1EE51, 0x1EE52, 2),
+    (0x1EE54, 0x1EE54, 2),
+    (0x1EE57, 0x1EE57, 2),
+    (0x1EE59, 0x1EE59, 2),
+    (0x1EE5B, 0x1EE5B, 2),
+    (0x1EE5D, 0x1EE5D, 2),
+    (0x1EE5F, 0x1EE5F, 2),
+    (0x1EE61, 0x1EE62, 2),
+    (0x1EE64, 0x1EE64, 2),
+    (0x1EE67, 0x1EE6A, 2),
+    (0x1EE6C, 0x1EE72, 2),
+    (0x1EE74, 0x1EE77, 2),
+    (0x1EE79, 0x1EE7C, 2),
+    (0x1EE7E, 0x1EE7E, 2),
+    (0x1EE80, 0x1EE89, 2),
+    (0x1EE8B, 0x1EE9B, 2),
+    (0x1EEA1, 0x1EEA3, 2),
+    (0x1EEA5, 0x1EEA9, 2),
+    (0x1EEAB, 0x1EEBB, 2),
+    (0x1EEF0, 0x1EEF1, 10),
+    (0x1F000, 0x1F02B, 10),
+    (0x1F030, 0x1F093, 10),
+    (0x1F0A0, 0x1F0AE, 10),
+    (0x1F0B1, 0x1F0BF, 10),
+    (0x1F0C1, 0x1F0CF, 10),
+    (0x1F0D1, 0x1F0F5, 10),
+    (0x1F100, 0x1F10A, 3),
+    (0x1F10B, 0x1F10F, 10),
+    (0x1F12F, 0x1F12F, 10),
+    (0x1F16A, 0x1F16F, 10),
+    (0x1F1AD, 0x1F1AD, 10),
+    (0x1F260, 0x1F265, 10),
+    (0x1F300, 0x1F6D7, 10),
+    (0x1F6DD, 0x1F6EC, 10),
+    (0x1F6F0, 0x1F6FC, 10),
+    (0x1F700, 0x1F773, 10),
+    (0x1F780, 0x1F7D8, 10),
+    (0x1F7E0, 0x1F7EB, 10),
+    (0x1F7F0, 0x1F7F0, 10),
+    (0x1F800, 0x1F80B, 10),
+    (0x1F810, 0x1F847, 10),
+    (0x1F850, 0x1F859, 10),
+    (0x1F860, 0x1F887, 10),
+    (0x1F890, 0x1F8AD, 10),
+    (0x1F8B0, 0x1F8B1, 10),
+    (0x1F900, 0x1FA53, 10),
+    (0x1FA60, 0x1FA6D, 10),
+    (0x1FA70, 0x1FA74, 10),
+    (0x1FA78, 0x1FA7C, 10),
+    (0x1FA80, 0x1FA86, 10),
+    (0x1FA90, 0x1FAAC, 10),
+    (0x1FAB0, 0x1FABA, 10),
+    (0x1FAC0, 0x1FAC5, 10),
+    (0x1FAD0, 0x1FAD9, 10),
+    (0x1FAE0, 0x1FAE7, 10),
+    (0x1FAF0, 0x1FAF6, 10),
+    (0x1FB00, 0x1FB92, 10),
+    (0x1FB94, 0x1FBCA, 10),
+    (0x1FBF0, 0x1FBF9, 3),
+    (0xE0001, 0xE0001, 9),
+    (0xE0020, 0xE007F, 9),
+    (0xE0100, 0xE01EF, 8),
+];
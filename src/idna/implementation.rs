@@ -1,5 +1,5 @@
 // Pure-Rust IDNA implementation — ported from Ada's ada_idna.cpp.
-// UTS #46 non-transitional processing.
+// UTS #46 non-transitional processing, with optional transitional mapping.
 
 #![allow(
     clippy::cast_lossless,
@@ -23,6 +23,12 @@ use core::cmp::Ordering;
 #[cfg(feature = "std")]
 use std::{cmp::Ordering, string::String, vec::Vec};
 
+use crate::error::ParseError;
+use crate::idna::bidi_tables::{
+    BIDI_AL, BIDI_AN, BIDI_BN, BIDI_CLASS, BIDI_CS, BIDI_EN, BIDI_ES, BIDI_ET, BIDI_L, BIDI_NSM,
+    BIDI_ON, BIDI_R,
+};
+use crate::idna::mark_tables::COMBINING_MARKS;
 use crate::idna::norm_tables::{
     CCC_BLOCK, CCC_INDEX, COMP_BLOCK, COMP_DATA, COMP_INDEX, DECOMP_BLOCK, DECOMP_DATA,
     DECOMP_INDEX,
@@ -53,9 +59,20 @@ pub(crate) fn find_range_index(key: u32) -> usize {
     if low == 0 { 0 } else { low - 1 }
 }
 
+/// Map a deviation character the way transitional processing does:
+/// ß → ss, ς → σ, and ZWJ/ZWNJ are removed.
+fn push_transitional(cp: u32, out: &mut Vec<u32>) {
+    match cp {
+        0x00DF => out.extend_from_slice(&[0x73, 0x73]),
+        0x03C2 => out.push(0x03C3),
+        0x200C | 0x200D => {}
+        _ => out.push(cp),
+    }
+}
+
 /// Apply UTS #46 IDNA mapping to a sequence of code points.
 /// Returns `None` if any disallowed code point is encountered.
-fn idna_map(input: &[u32]) -> Option<Vec<u32>> {
+fn idna_map(input: &[u32], transitional: bool) -> Option<Vec<u32>> {
     let mut out = Vec::with_capacity(input.len());
     for &cp in input {
         let idx = find_range_index(cp);
@@ -73,6 +90,18 @@ fn idna_map(input: &[u32]) -> Option<Vec<u32>> {
             }
         }
     }
+    // Deviations can also come out of the table (ẞ → ß), so map them afterwards
+    if transitional
+        && out
+            .iter()
+            .any(|&cp| matches!(cp, 0x00DF | 0x03C2 | 0x200C | 0x200D))
+    {
+        let mut mapped = Vec::with_capacity(out.len() + 1);
+        for cp in out {
+            push_transitional(cp, &mut mapped);
+        }
+        return Some(mapped);
+    }
     Some(out)
 }
 
@@ -507,8 +536,21 @@ pub fn punycode_encode(input: &[u32]) -> Option<String> {
 // Label validation helpers
 // ============================================================
 
-fn is_combining_start(cp: u32) -> bool {
-    ccc(cp) != 0
+/// Check whether a code point is a combining mark (`General_Category` Mn,
+/// Mc or Me); many marks, e.g. U+0903 DEVANAGARI SIGN VISARGA, have a
+/// canonical combining class of 0
+fn is_combining_mark(cp: u32) -> bool {
+    COMBINING_MARKS
+        .binary_search_by(|&(first, last)| {
+            if last < cp {
+                Ordering::Less
+            } else if first > cp {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
 }
 
 fn label_is_valid(label: &[u32]) -> bool {
@@ -516,7 +558,7 @@ fn label_is_valid(label: &[u32]) -> bool {
         return true;
     }
     // Must not start with a combining mark
-    if is_combining_start(label[0]) {
+    if is_combining_mark(label[0]) {
         return false;
     }
     true
@@ -568,33 +610,79 @@ fn is_arabic_hebrew(cp: u32) -> bool {
     )
 }
 
-/// Bidi character classes (simplified: L=left-to-right, R/AL=right-to-left)
+/// Bidi class of a code point, from the Unicode `Bidi_Class` property
 fn bidi_class(cp: u32) -> u8 {
-    // Common Arabic range (AL)
-    if (0x0600..=0x08FF).contains(&cp) {
-        return 2;
-    } // RTL/Arabic
-    // Hebrew range (R)
-    if (0x0590..=0x05FF).contains(&cp) {
-        return 2;
-    } // RTL
-    // Arabic Presentation Forms
-    if (0xFB1D..=0xFDFF).contains(&cp) || (0xFE70..=0xFEFF).contains(&cp) {
-        return 2;
-    }
-    // Most Latin/ASCII: L
-    if cp < 0x0590 {
-        return 1;
-    }
-    // Default: non-strongly directional
-    0
+    match BIDI_CLASS.binary_search_by(|&(first, last, _)| {
+        if last < cp {
+            Ordering::Less
+        } else if first > cp {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(index) => BIDI_CLASS[index].2,
+        Err(_) => BIDI_L,
+    }
 }
 
-/// Validate Context-J rules (U+200C, U+200D) and basic Bidi rules.
-pub(crate) fn validate_context_and_bidi(label: &[u32]) -> bool {
-    let mut has_rtl = false;
-    let mut has_ltr = false;
+/// Check the Bidi rule of RFC 5893 section 2 on one label
+fn label_satisfies_bidi_rule(label: &[u32]) -> bool {
+    let Some(&first) = label.first() else {
+        return true;
+    };
+    // Trailing NSMs don't count for the end-of-label rules
+    let last = label
+        .iter()
+        .rev()
+        .map(|&cp| bidi_class(cp))
+        .find(|&class| class != BIDI_NSM);
+
+    match bidi_class(first) {
+        // Rules 2-4: RTL label
+        BIDI_R | BIDI_AL => {
+            let mut has_en = false;
+            let mut has_an = false;
+            for &cp in label {
+                match bidi_class(cp) {
+                    BIDI_EN => has_en = true,
+                    BIDI_AN => has_an = true,
+                    BIDI_R | BIDI_AL | BIDI_ES | BIDI_CS | BIDI_ET | BIDI_ON | BIDI_BN
+                    | BIDI_NSM => {}
+                    _ => return false,
+                }
+            }
+            !(has_en && has_an) && matches!(last, Some(BIDI_R | BIDI_AL | BIDI_EN | BIDI_AN))
+        }
+        // Rules 5-6: LTR label
+        BIDI_L => {
+            label.iter().all(|&cp| {
+                matches!(
+                    bidi_class(cp),
+                    BIDI_L | BIDI_EN | BIDI_ES | BIDI_CS | BIDI_ET | BIDI_ON | BIDI_BN | BIDI_NSM
+                )
+            }) && matches!(last, Some(BIDI_L | BIDI_EN))
+        }
+        // Rule 1: the first character must be L, R or AL
+        _ => false,
+    }
+}
+
+/// Check the Bidi rule (UTS #46 `CheckBidi`) on the decoded labels of a
+/// domain
+///
+/// The rule only applies to Bidi domain names, i.e. those containing an
+/// R, AL or AN character; then every label must satisfy it.
+fn domain_satisfies_bidi_rule(labels: &[Vec<u32>]) -> bool {
+    let is_bidi_domain = labels
+        .iter()
+        .flatten()
+        .any(|&cp| matches!(bidi_class(cp), BIDI_R | BIDI_AL | BIDI_AN));
+    !is_bidi_domain || labels.iter().all(|label| label_satisfies_bidi_rule(label))
+}
 
+/// Validate Context-J rules (U+200C, U+200D).
+pub(crate) fn validate_context_j(label: &[u32]) -> bool {
     for (i, &cp) in label.iter().enumerate() {
         // Context-J: U+200D (ZERO WIDTH JOINER)
         if cp == 0x200D {
@@ -621,40 +709,28 @@ pub(crate) fn validate_context_and_bidi(label: &[u32]) -> bool {
                 return false;
             }
         }
-        let bc = bidi_class(cp);
-        if bc == 1 {
-            has_ltr = true;
-        }
-        if bc == 2 {
-            has_rtl = true;
-        }
-    }
-
-    // Simplified Bidi: a label must not mix strongly LTR and strongly RTL characters
-    if has_ltr && has_rtl {
-        return false;
     }
     true
 }
 
-fn process_label(label_str: &str) -> Option<String> {
+fn process_label(label_str: &str, transitional: bool) -> Result<String, ParseError> {
     // Validate xn-- (ACE) labels: the punycode must decode correctly and
     // every decoded code point must be IDNA-valid.
     if let Some(puny_part) = label_str.strip_prefix("xn--") {
         if puny_part.is_empty() {
-            return None; // "xn--" with no payload is invalid
+            return Err(ParseError::IdnaError); // "xn--" with no payload is invalid
         }
         let mut decoded_buf = [0u32; 64];
         let mut decoded_len = 0usize;
         if !punycode_decode_into(puny_part, &mut decoded_buf, &mut decoded_len) {
-            return None; // punycode decode failure
+            return Err(ParseError::IdnaError); // punycode decode failure
         }
         let decoded = &decoded_buf[..decoded_len];
         if decoded.iter().all(|&cp| cp < 0x80) {
-            return None; // all-ASCII should not be punycode-encoded
+            return Err(ParseError::IdnaError); // all-ASCII should not be punycode-encoded
         }
         if !label_is_valid(decoded) {
-            return None;
+            return Err(ParseError::InvalidHost);
         }
         // Every decoded code point must have IDNA status = valid (1).
         // Ignored (0), disallowed (2), or mapped (≥3) → invalid label.
@@ -662,14 +738,15 @@ fn process_label(label_str: &str) -> Option<String> {
             let idx = find_range_index(cp);
             let status = TABLE[idx][1] & 0xFF;
             if status != 1 {
-                return None;
+                return Err(ParseError::IdnaError);
             }
         }
-        // Check Context-J rules and Bidi rules on the decoded label.
-        if !validate_context_and_bidi(decoded) {
-            return None;
+        // Check Context-J rules on the decoded label; the Bidi rule needs
+        // the whole domain and is checked in `domain_to_ascii_with`.
+        if !validate_context_j(decoded) {
+            return Err(ParseError::IdnaError);
         }
-        return Some(String::from(label_str)); // keep the xn-- form unchanged
+        return Ok(String::from(label_str)); // keep the xn-- form unchanged
     }
 
     // Fast path: pure lowercase ASCII label (no mapping needed)
@@ -679,41 +756,65 @@ fn process_label(label_str: &str) -> Option<String> {
         .all(|&b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
     {
         if !label_str.is_empty() {
-            return Some(String::from(label_str));
+            return Ok(String::from(label_str));
         }
-        return Some(String::new());
+        return Ok(String::new());
     }
 
     // Decode to code points
     let codepoints: Vec<u32> = label_str.chars().map(|c| c as u32).collect();
     // Map
-    let mapped = idna_map(&codepoints)?;
+    let mapped = idna_map(&codepoints, transitional).ok_or(ParseError::IdnaError)?;
     // Normalize (NFC)
     let normalized = nfc(&mapped);
     if normalized.is_empty() {
-        return Some(String::new());
+        return Ok(String::new());
     }
     // Validate
     if !label_is_valid(&normalized) {
-        return None;
+        return Err(ParseError::InvalidHost);
     }
-    // Validate Context-J and Bidi rules on the normalized label
-    if !validate_context_and_bidi(&normalized) {
-        return None;
+    // Validate Context-J rules on the normalized label
+    if !validate_context_j(&normalized) {
+        return Err(ParseError::IdnaError);
     }
 
     // All ASCII?
     let all_ascii = normalized.iter().all(|&cp| cp < 0x80);
     if all_ascii {
         let s: String = normalized.iter().map(|&cp| cp as u8 as char).collect();
-        return Some(s);
+        return Ok(s);
     }
     // Punycode encode
-    let encoded = punycode_encode(&normalized)?;
+    let encoded = punycode_encode(&normalized).ok_or(ParseError::IdnaError)?;
     {
         let mut label = String::from("xn--");
         label.push_str(&encoded);
-        Some(label)
+        Ok(label)
+    }
+}
+
+/// Decode Punycode (without the xn-- prefix) into a vector of any length
+///
+/// Each decoded code point consumes at least one input byte, so the input
+/// length bounds the output. Returns `None` on decode failure.
+pub(crate) fn punycode_decode(input: &str) -> Option<Vec<u32>> {
+    let mut decoded: Vec<u32> = core::iter::repeat_n(0, input.len()).collect();
+    let mut decoded_len = 0usize;
+    if !punycode_decode_into(input, &mut decoded, &mut decoded_len) {
+        return None;
+    }
+    decoded.truncate(decoded_len);
+    Some(decoded)
+}
+
+/// Code points of a processed label, decoding the Punycode of xn-- labels
+///
+/// Returns `None` if the Punycode does not decode.
+fn decoded_label(label: &str) -> Option<Vec<u32>> {
+    match label.strip_prefix("xn--") {
+        Some(puny_part) => punycode_decode(puny_part),
+        None => Some(label.chars().map(|c| c as u32).collect()),
     }
 }
 
@@ -723,12 +824,20 @@ fn process_label(label_str: &str) -> Option<String> {
 
 /// Convert a (potentially internationalized) domain name to its
 /// ASCII-compatible encoding (ACE / Punycode).
-/// Returns `None` on failure.
-pub fn domain_to_ascii(input: &str) -> Option<String> {
+/// Returns `ParseError::InvalidHost` if a label starts with a combining
+/// mark or the domain breaks the Bidi rule, and `ParseError::IdnaError` on
+/// any other failure.
+pub fn domain_to_ascii(input: &str) -> Result<String, ParseError> {
+    domain_to_ascii_with(input, false)
+}
+
+/// Like `domain_to_ascii`, optionally using transitional processing
+/// for the deviation characters (ß, ς, ZWJ, ZWNJ).
+pub fn domain_to_ascii_with(input: &str, transitional: bool) -> Result<String, ParseError> {
     // Decode from UTF-8 to code points
     let codepoints: Vec<u32> = input.chars().map(|c| c as u32).collect();
     // Apply IDNA mapping to the whole input (handles case fold, etc.)
-    let mapped = idna_map(&codepoints)?;
+    let mapped = idna_map(&codepoints, transitional).ok_or(ParseError::IdnaError)?;
     // NFC the whole thing
     let normalized = nfc(&mapped);
 
@@ -746,19 +855,31 @@ pub fn domain_to_ascii(input: &str) -> Option<String> {
             result.push('.');
         }
         first = false;
-        let processed = process_label(label_str)?;
+        let processed = process_label(label_str, transitional)?;
         result.push_str(&processed);
     }
     if result.is_empty() {
-        return None;
+        return Err(ParseError::IdnaError);
+    }
+
+    // Only non-ASCII labels can make this a Bidi domain name
+    if result.starts_with("xn--") || result.contains(".xn--") {
+        let labels: Vec<Vec<u32>> = result
+            .split('.')
+            .map(decoded_label)
+            .collect::<Option<_>>()
+            .ok_or(ParseError::IdnaError)?;
+        if !domain_satisfies_bidi_rule(&labels) {
+            return Err(ParseError::InvalidHost);
+        }
     }
 
     // Reject if the final result contains any forbidden domain code points
     // (e.g. ':', space, control characters that slipped through IDNA mapping).
     for b in result.bytes() {
         if crate::unicode::is_forbidden_domain_code_point(b) {
-            return None;
+            return Err(ParseError::IdnaError);
         }
     }
-    Some(result)
+    Ok(result)
 }
@@ -0,0 +1,754 @@
+#![allow(clippy::unreadable_literal)]
+
+// Auto-generated from the Unicode 14.0 Script property. DO NOT EDIT.
+// Common, Inherited and unassigned code points are not listed.
+
+pub const SCRIPT_BOPOMOFO: u8 = 12;
+pub const SCRIPT_HAN: u8 = 46;
+pub const SCRIPT_HANGUL: u8 = 47;
+pub const SCRIPT_HIRAGANA: u8 = 52;
+pub const SCRIPT_KATAKANA: u8 = 59;
+
+/// `(first, last, script)` ranges sorted by code point; scripts are
+/// numbered in alphabetical order of their names
+pub static SCRIPT: [(u32, u32, u8); 739] = [
+    (0x41, 0x5A, 67),
+    (0x61, 0x7A, 67),
+    (0xAA, 0xAA, 67),
+    (0xBA, 0xBA, 67),
+    (0xC0, 0xD6, 67),
+    (0xD8, 0xF6, 67),
+    (0xF8, 0x2B8, 67),
+    (0x2E0, 0x2E4, 67),
+    (0x2EA, 0x2EB, 12),
+    (0x370, 0x373, 42),
+    (0x375, 0x377, 42),
+    (0x37A, 0x37D, 42),
+    (0x37F, 0x37F, 42),
+    (0x384, 0x384, 42),
+    (0x386, 0x386, 42),
+    (0x388, 0x38A, 42),
+    (0x38C, 0x38C, 42),
+    (0x38E, 0x3A1, 42),
+    (0x3A3, 0x3E1, 42),
+    (0x3E2, 0x3EF, 24),
+    (0x3F0, 0x3FF, 42),
+    (0x400, 0x484, 28),
+    (0x487, 0x52F, 28),
+    (0x531, 0x556, 4),
+    (0x559, 0x58A, 4),
+    (0x58D, 0x58F, 4),
+    (0x591, 0x5C7, 51),
+    (0x5D0, 0x5EA, 51),
+    (0x5EF, 0x5F4, 51),
+    (0x600, 0x604, 3),
+    (0x606, 0x60B, 3),
+    (0x60D, 0x61A, 3),
+    (0x61C, 0x61E, 3),
+    (0x620, 0x63F, 3),
+    (0x641, 0x64A, 3),
+    (0x656, 0x66F, 3),
+    (0x671, 0x6DC, 3),
+    (0x6DE, 0x6FF, 3),
+    (0x700, 0x70D, 134),
+    (0x70F, 0x74A, 134),
+    (0x74D, 0x74F, 134),
+    (0x750, 0x77F, 3),
+    (0x780, 0x7B1, 145),
+    (0x7C0, 0x7FA, 97),
+    (0x7FD, 0x7FF, 97),
+    (0x800, 0x82D, 122),
+    (0x830, 0x83E, 122),
+    (0x840, 0x85B, 78),
+    (0x85E, 0x85E, 78),
+    (0x860, 0x86A, 134),
+    (0x870, 0x88E, 3),
+    (0x890, 0x891, 3),
+    (0x898, 0x8E1, 3),
+    (0x8E3, 0x8FF, 3),
+    (0x900, 0x950, 30),
+    (0x955, 0x963, 30),
+    (0x966, 0x97F, 30),
+    (0x980, 0x983, 10),
+    (0x985, 0x98C, 10),
+    (0x98F, 0x990, 10),
+    (0x993, 0x9A8, 10),
+    (0x9AA, 0x9B0, 10),
+    (0x9B2, 0x9B2, 10),
+    (0x9B6, 0x9B9, 10),
+    (0x9BC, 0x9C4, 10),
+    (0x9C7, 0x9C8, 10),
+    (0x9CB, 0x9CE, 10),
+    (0x9D7, 0x9D7, 10),
+    (0x9DC, 0x9DD, 10),
+    (0x9DF, 0x9E3, 10),
+    (0x9E6, 0x9FE, 10),
+    (0xA01, 0xA03, 45),
+    (0xA05, 0xA0A, 45),
+    (0xA0F, 0xA10, 45),
+    (0xA13, 0xA28, 45),
+    (0xA2A, 0xA30, 45),
+    (0xA32, 0xA33, 45),
+    (0xA35, 0xA36, 45),
+    (0xA38, 0xA39, 45),
+    (0xA3C, 0xA3C, 45),
+    (0xA3E, 0xA42, 45),
+    (0xA47, 0xA48, 45),
+    (0xA4B, 0xA4D, 45),
+    (0xA51, 0xA51, 45),
+    (0xA59, 0xA5C, 45),
+    (0xA5E, 0xA5E, 45),
+    (0xA66, 0xA76, 45),
+    (0xA81, 0xA83, 43),
+    (0xA85, 0xA8D, 43),
+    (0xA8F, 0xA91, 43),
+    (0xA93, 0xAA8, 43),
+    (0xAAA, 0xAB0, 43),
+    (0xAB2, 0xAB3, 43),
+    (0xAB5, 0xAB9, 43),
+    (0xABC, 0xAC5, 43),
+    (0xAC7, 0xAC9, 43),
+    (0xACB, 0xACD, 43),
+    (0xAD0, 0xAD0, 43),
+    (0xAE0, 0xAE3, 43),
+    (0xAE6, 0xAF1, 43),
+    (0xAF9, 0xAFF, 43),
+    (0xB01, 0xB03, 111),
+    (0xB05, 0xB0C, 111),
+    (0xB0F, 0xB10, 111),
+    (0xB13, 0xB28, 111),
+    (0xB2A, 0xB30, 111),
+    (0xB32, 0xB33, 111),
+    (0xB35, 0xB39, 111),
+    (0xB3C, 0xB44, 111),
+    (0xB47, 0xB48, 111),
+    (0xB4B, 0xB4D, 111),
+    (0xB55, 0xB57, 111),
+    (0xB5C, 0xB5D, 111),
+    (0xB5F, 0xB63, 111),
+    (0xB66, 0xB77, 111),
+    (0xB82, 0xB83, 141),
+    (0xB85, 0xB8A, 141),
+    (0xB8E, 0xB90, 141),
+    (0xB92, 0xB95, 141),
+    (0xB99, 0xB9A, 141),
+    (0xB9C, 0xB9C, 141),
+    (0xB9E, 0xB9F, 141),
+    (0xBA3, 0xBA4, 141),
+    (0xBA8, 0xBAA, 141),
+    (0xBAE, 0xBB9, 141),
+    (0xBBE, 0xBC2, 141),
+    (0xBC6, 0xBC8, 141),
+    (0xBCA, 0xBCD, 141),
+    (0xBD0, 0xBD0, 141),
+    (0xBD7, 0xBD7, 141),
+    (0xBE6, 0xBFA, 141),
+    (0xC00, 0xC0C, 144),
+    (0xC0E, 0xC10, 144),
+    (0xC12, 0xC28, 144),
+    (0xC2A, 0xC39, 144),
+    (0xC3C, 0xC44, 144),
+    (0xC46, 0xC48, 144),
+    (0xC4A, 0xC4D, 144),
+    (0xC55, 0xC56, 144),
+    (0xC58, 0xC5A, 144),
+    (0xC5D, 0xC5D, 144),
+    (0xC60, 0xC63, 144),
+    (0xC66, 0xC6F, 144),
+    (0xC77, 0xC7F, 144),
+    (0xC80, 0xC8C, 58),
+    (0xC8E, 0xC90, 58),
+    (0xC92, 0xCA8, 58),
+    (0xCAA, 0xCB3, 58),
+    (0xCB5, 0xCB9, 58),
+    (0xCBC, 0xCC4, 58),
+    (0xCC6, 0xCC8, 58),
+    (0xCCA, 0xCCD, 58),
+    (0xCD5, 0xCD6, 58),
+    (0xCDD, 0xCDE, 58),
+    (0xCE0, 0xCE3, 58),
+    (0xCE6, 0xCEF, 58),
+    (0xCF1, 0xCF2, 58),
+    (0xD00, 0xD0C, 77),
+    (0xD0E, 0xD10, 77),
+    (0xD12, 0xD44, 77),
+    (0xD46, 0xD48, 77),
+    (0xD4A, 0xD4F, 77),
+    (0xD54, 0xD63, 77),
+    (0xD66, 0xD7F, 77),
+    (0xD81, 0xD83, 128),
+    (0xD85, 0xD96, 128),
+    (0xD9A, 0xDB1, 128),
+    (0xDB3, 0xDBB, 128),
+    (0xDBD, 0xDBD, 128),
+    (0xDC0, 0xDC6, 128),
+    (0xDCA, 0xDCA, 128),
+    (0xDCF, 0xDD4, 128),
+    (0xDD6, 0xDD6, 128),
+    (0xDD8, 0xDDF, 128),
+    (0xDE6, 0xDEF, 128),
+    (0xDF2, 0xDF4, 128),
+    (0xE01, 0xE3A, 146),
+    (0xE40, 0xE5B, 146),
+    (0xE81, 0xE82, 66),
+    (0xE84, 0xE84, 66),
+    (0xE86, 0xE8A, 66),
+    (0xE8C, 0xEA3, 66),
+    (0xEA5, 0xEA5, 66),
+    (0xEA7, 0xEBD, 66),
+    (0xEC0, 0xEC4, 66),
+    (0xEC6, 0xEC6, 66),
+    (0xEC8, 0xECD, 66),
+    (0xED0, 0xED9, 66),
+    (0xEDC, 0xEDF, 66),
+    (0xF00, 0xF47, 147),
+    (0xF49, 0xF6C, 147),
+    (0xF71, 0xF97, 147),
+    (0xF99, 0xFBC, 147),
+    (0xFBE, 0xFCC, 147),
+    (0xFCE, 0xFD4, 147),
+    (0xFD9, 0xFDA, 147),
+    (0x1000, 0x109F, 92),
+    (0x10A0, 0x10C5, 38),
+    (0x10C7, 0x10C7, 38),
+    (0x10CD, 0x10CD, 38),
+    (0x10D0, 0x10FA, 38),
+    (0x10FC, 0x10FF, 38),
+    (0x1100, 0x11FF, 47),
+    (0x1200, 0x1248, 37),
+    (0x124A, 0x124D, 37),
+    (0x1250, 0x1256, 37),
+    (0x1258, 0x1258, 37),
+    (0x125A, 0x125D, 37),
+    (0x1260, 0x1288, 37),
+    (0x128A, 0x128D, 37),
+    (0x1290, 0x12B0, 37),
+    (0x12B2, 0x12B5, 37),
+    (0x12B8, 0x12BE, 37),
+    (0x12C0, 0x12C0, 37),
+    (0x12C2, 0x12C5, 37),
+    (0x12C8, 0x12D6, 37),
+    (0x12D8, 0x1310, 37),
+    (0x1312, 0x1315, 37),
+    (0x1318, 0x135A, 37),
+    (0x135D, 0x137C, 37),
+    (0x1380, 0x1399, 37),
+    (0x13A0, 0x13F5, 22),
+    (0x13F8, 0x13FD, 22),
+    (0x1400, 0x167F, 17),
+    (0x1680, 0x169C, 100),
+    (0x16A0, 0x16EA, 121),
+    (0x16EE, 0x16F8, 121),
+    (0x1700, 0x1715, 135),
+    (0x171F, 0x171F, 135),
+    (0x1720, 0x1734, 49),
+    (0x1740, 0x1753, 16),
+    (0x1760, 0x176C, 136),
+    (0x176E, 0x1770, 136),
+    (0x1772, 0x1773, 136),
+    (0x1780, 0x17DD, 63),
+    (0x17E0, 0x17E9, 63),
+    (0x17F0, 0x17F9, 63),
+    (0x1800, 0x1801, 89),
+    (0x1804, 0x1804, 89),
+    (0x1806, 0x1819, 89),
+    (0x1820, 0x1878, 89),
+    (0x1880, 0x18AA, 89),
+    (0x18B0, 0x18F5, 17),
+    (0x1900, 0x191E, 69),
+    (0x1920, 0x192B, 69),
+    (0x1930, 0x193B, 69),
+    (0x1940, 0x1940, 69),
+    (0x1944, 0x194F, 69),
+    (0x1950, 0x196D, 137),
+    (0x1970, 0x1974, 137),
+    (0x1980, 0x19AB, 95),
+    (0x19B0, 0x19C9, 95),
+    (0x19D0, 0x19DA, 95),
+    (0x19DE, 0x19DF, 95),
+    (0x19E0, 0x19FF, 63),
+    (0x1A00, 0x1A1B, 15),
+    (0x1A1E, 0x1A1F, 15),
+    (0x1A20, 0x1A5E, 138),
+    (0x1A60, 0x1A7C, 138),
+    (0x1A7F, 0x1A89, 138),
+    (0x1A90, 0x1A99, 138),
+    (0x1AA0, 0x1AAD, 138),
+    (0x1B00, 0x1B4C, 6),
+    (0x1B50, 0x1B7E, 6),
+    (0x1B80, 0x1BBF, 132),
+    (0x1BC0, 0x1BF3, 9),
+    (0x1BFC, 0x1BFF, 9),
+    (0x1C00, 0x1C37, 68),
+    (0x1C3B, 0x1C49, 68),
+    (0x1C4D, 0x1C4F, 68),
+    (0x1C50, 0x1C7F, 101),
+    (0x1C80, 0x1C88, 28),
+    (0x1C90, 0x1CBA, 38),
+    (0x1CBD, 0x1CBF, 38),
+    (0x1CC0, 0x1CC7, 132),
+    (0x1D00, 0x1D25, 67),
+    (0x1D26, 0x1D2A, 42),
+    (0x1D2B, 0x1D2B, 28),
+    (0x1D2C, 0x1D5C, 67),
+    (0x1D5D, 0x1D61, 42),
+    (0x1D62, 0x1D65, 67),
+    (0x1D66, 0x1D6A, 42),
+    (0x1D6B, 0x1D77, 67),
+    (0x1D78, 0x1D78, 28),
+    (0x1D79, 0x1DBE, 67),
+    (0x1DBF, 0x1DBF, 42),
+    (0x1E00, 0x1EFF, 67),
+    (0x1F00, 0x1F15, 42),
+    (0x1F18, 0x1F1D, 42),
+    (0x1F20, 0x1F45, 42),
+    (0x1F48, 0x1F4D, 42),
+    (0x1F50, 0x1F57, 42),
+    (0x1F59, 0x1F59, 42),
+    (0x1F5B, 0x1F5B, 42),
+    (0x1F5D, 0x1F5D, 42),
+    (0x1F5F, 0x1F7D, 42),
+    (0x1F80, 0x1FB4, 42),
+    (0x1FB6, 0x1FC4, 42),
+    (0x1FC6, 0x1FD3, 42),
+    (0x1FD6, 0x1FDB, 42),
+    (0x1FDD, 0x1FEF, 42),
+    (0x1FF2, 0x1FF4, 42),
+    (0x1FF6, 0x1FFE, 42),
+    (0x2071, 0x2071, 67),
+    (0x207F, 0x207F, 67),
+    (0x2090, 0x209C, 67),
+    (0x2126, 0x2126, 42),
+    (0x212A, 0x212B, 67),
+    (0x2132, 0x2132, 67),
+    (0x214E, 0x214E, 67),
+    (0x2160, 0x2188, 67),
+    (0x2800, 0x28FF, 14),
+    (0x2C00, 0x2C5F, 39),
+    (0x2C60, 0x2C7F, 67),
+    (0x2C80, 0x2CF3, 24),
+    (0x2CF9, 0x2CFF, 24),
+    (0x2D00, 0x2D25, 38),
+    (0x2D27, 0x2D27, 38),
+    (0x2D2D, 0x2D2D, 38),
+    (0x2D30, 0x2D67, 148),
+    (0x2D6F, 0x2D70, 148),
+    (0x2D7F, 0x2D7F, 148),
+    (0x2D80, 0x2D96, 37),
+    (0x2DA0, 0x2DA6, 37),
+    (0x2DA8, 0x2DAE, 37),
+    (0x2DB0, 0x2DB6, 37),
+    (0x2DB8, 0x2DBE, 37),
+    (0x2DC0, 0x2DC6, 37),
+    (0x2DC8, 0x2DCE, 37),
+    (0x2DD0, 0x2DD6, 37),
+    (0x2DD8, 0x2DDE, 37),
+    (0x2DE0, 0x2DFF, 28),
+    (0x2E80, 0x2E99, 46),
+    (0x2E9B, 0x2EF3, 46),
+    (0x2F00, 0x2FD5, 46),
+    (0x3005, 0x3005, 46),
+    (0x3007, 0x3007, 46),
+    (0x3021, 0x3029, 46),
+    (0x302E, 0x302F, 47),
+    (0x3038, 0x303B, 46),
+    (0x3041, 0x3096, 52),
+    (0x309D, 0x309F, 52),
+    (0x30A1, 0x30FA, 59),
+    (0x30FD, 0x30FF, 59),
+    (0x3105, 0x312F, 12),
+    (0x3131, 0x318E, 47),
+    (0x31A0, 0x31BF, 12),
+    (0x31F0, 0x31FF, 59),
+    (0x3200, 0x321E, 47),
+    (0x3260, 0x327E, 47),
+    (0x32D0, 0x32FE, 59),
+    (0x3300, 0x3357, 59),
+    (0x3400, 0x4DBF, 46),
+    (0x4E00, 0x9FFF, 46),
+    (0xA000, 0xA48C, 157),
+    (0xA490, 0xA4C6, 157),
+    (0xA4D0, 0xA4FF, 72),
+    (0xA500, 0xA62B, 152),
+    (0xA640, 0xA69F, 28),
+    (0xA6A0, 0xA6F7, 7),
+    (0xA722, 0xA787, 67),
+    (0xA78B, 0xA7CA, 67),
+    (0xA7D0, 0xA7D1, 67),
+    (0xA7D3, 0xA7D3, 67),
+    (0xA7D5, 0xA7D9, 67),
+    (0xA7F2, 0xA7FF, 67),
+    (0xA800, 0xA82C, 133),
+    (0xA840, 0xA877, 117),
+    (0xA880, 0xA8C5, 123),
+    (0xA8CE, 0xA8D9, 123),
+    (0xA8E0, 0xA8FF, 30),
+    (0xA900, 0xA92D, 60),
+    (0xA92F, 0xA92F, 60),
+    (0xA930, 0xA953, 120),
+    (0xA95F, 0xA95F, 120),
+    (0xA960, 0xA97C, 47),
+    (0xA980, 0xA9CD, 56),
+    (0xA9D0, 0xA9D9, 56),
+    (0xA9DE, 0xA9DF, 56),
+    (0xA9E0, 0xA9FE, 92),
+    (0xAA00, 0xAA36, 21),
+    (0xAA40, 0xAA4D, 21),
+    (0xAA50, 0xAA59, 21),
+    (0xAA5C, 0xAA5F, 21),
+    (0xAA60, 0xAA7F, 92),
+    (0xAA80, 0xAAC2, 139),
+    (0xAADB, 0xAADF, 139),
+    (0xAAE0, 0xAAF6, 83),
+    (0xAB01, 0xAB06, 37),
+    (0xAB09, 0xAB0E, 37),
+    (0xAB11, 0xAB16, 37),
+    (0xAB20, 0xAB26, 37),
+    (0xAB28, 0xAB2E, 37),
+    (0xAB30, 0xAB5A, 67),
+    (0xAB5C, 0xAB64, 67),
+    (0xAB65, 0xAB65, 42),
+    (0xAB66, 0xAB69, 67),
+    (0xAB70, 0xABBF, 22),
+    (0xABC0, 0xABED, 83),
+    (0xABF0, 0xABF9, 83),
+    (0xAC00, 0xD7A3, 47),
+    (0xD7B0, 0xD7C6, 47),
+    (0xD7CB, 0xD7FB, 47),
+    (0xF900, 0xFA6D, 46),
+    (0xFA70, 0xFAD9, 46),
+    (0xFB00, 0xFB06, 67),
+    (0xFB13, 0xFB17, 4),
+    (0xFB1D, 0xFB36, 51),
+    (0xFB38, 0xFB3C, 51),
+    (0xFB3E, 0xFB3E, 51),
+    (0xFB40, 0xFB41, 51),
+    (0xFB43, 0xFB44, 51),
+    (0xFB46, 0xFB4F, 51),
+    (0xFB50, 0xFBC2, 3),
+    (0xFBD3, 0xFD3D, 3),
+    (0xFD40, 0xFD8F, 3),
+    (0xFD92, 0xFDC7, 3),
+    (0xFDCF, 0xFDCF, 3),
+    (0xFDF0, 0xFDFF, 3),
+    (0xFE2E, 0xFE2F, 28),
+    (0xFE70, 0xFE74, 3),
+    (0xFE76, 0xFEFC, 3),
+    (0xFF21, 0xFF3A, 67),
+    (0xFF41, 0xFF5A, 67),
+    (0xFF66, 0xFF6F, 59),
+    (0xFF71, 0xFF9D, 59),
+    (0xFFA0, 0xFFBE, 47),
+    (0xFFC2, 0xFFC7, 47),
+    (0xFFCA, 0xFFCF, 47),
+    (0xFFD2, 0xFFD7, 47),
+    (0xFFDA, 0xFFDC, 47),
+    (0x10000, 0x1000B, 71),
+    (0x1000D, 0x10026, 71),
+    (0x10028, 0x1003A, 71),
+    (0x1003C, 0x1003D, 71),
+    (0x1003F, 0x1004D, 71),
+    (0x10050, 0x1005D, 71),
+    (0x10080, 0x100FA, 71),
+    (0x10140, 0x1018E, 42),
+    (0x101A0, 0x101A0, 42),
+    (0x10280, 0x1029C, 73),
+    (0x102A0, 0x102D0, 18),
+    (0x10300, 0x10323, 103),
+    (0x1032D, 0x1032F, 103),
+    (0x10330, 0x1034A, 40),
+    (0x10350, 0x1037A, 105),
+    (0x10380, 0x1039D, 151),
+    (0x1039F, 0x1039F, 151),
+    (0x103A0, 0x103C3, 106),
+    (0x103C8, 0x103D5, 106),
+    (0x10400, 0x1044F, 29),
+    (0x10450, 0x1047F, 125),
+    (0x10480, 0x1049D, 113),
+    (0x104A0, 0x104A9, 113),
+    (0x104B0, 0x104D3, 112),
+    (0x104D8, 0x104FB, 112),
+    (0x10500, 0x10527, 35),
+    (0x10530, 0x10563, 19),
+    (0x1056F, 0x1056F, 19),
+    (0x10570, 0x1057A, 153),
+    (0x1057C, 0x1058A, 153),
+    (0x1058C, 0x10592, 153),
+    (0x10594, 0x10595, 153),
+    (0x10597, 0x105A1, 153),
+    (0x105A3, 0x105B1, 153),
+    (0x105B3, 0x105B9, 153),
+    (0x105BB, 0x105BC, 153),
+    (0x10600, 0x10736, 70),
+    (0x10740, 0x10755, 70),
+    (0x10760, 0x10767, 70),
+    (0x10780, 0x10785, 67),
+    (0x10787, 0x107B0, 67),
+    (0x107B2, 0x107BA, 67),
+    (0x10800, 0x10805, 26),
+    (0x10808, 0x10808, 26),
+    (0x1080A, 0x10835, 26),
+    (0x10837, 0x10838, 26),
+    (0x1083C, 0x1083C, 26),
+    (0x1083F, 0x1083F, 26),
+    (0x10840, 0x10855, 53),
+    (0x10857, 0x1085F, 53),
+    (0x10860, 0x1087F, 115),
+    (0x10880, 0x1089E, 93),
+    (0x108A7, 0x108AF, 93),
+    (0x108E0, 0x108F2, 50),
+    (0x108F4, 0x108F5, 50),
+    (0x108FB, 0x108FF, 50),
+    (0x10900, 0x1091B, 118),
+    (0x1091F, 0x1091F, 118),
+    (0x10920, 0x10939, 74),
+    (0x1093F, 0x1093F, 74),
+    (0x10980, 0x1099F, 86),
+    (0x109A0, 0x109B7, 85),
+    (0x109BC, 0x109CF, 85),
+    (0x109D2, 0x109FF, 85),
+    (0x10A00, 0x10A03, 61),
+    (0x10A05, 0x10A06, 61),
+    (0x10A0C, 0x10A13, 61),
+    (0x10A15, 0x10A17, 61),
+    (0x10A19, 0x10A35, 61),
+    (0x10A38, 0x10A3A, 61),
+    (0x10A3F, 0x10A48, 61),
+    (0x10A50, 0x10A58, 61),
+    (0x10A60, 0x10A7F, 108),
+    (0x10A80, 0x10A9F, 104),
+    (0x10AC0, 0x10AE6, 79),
+    (0x10AEB, 0x10AF6, 79),
+    (0x10B00, 0x10B35, 5),
+    (0x10B39, 0x10B3F, 5),
+    (0x10B40, 0x10B55, 55),
+    (0x10B58, 0x10B5F, 55),
+    (0x10B60, 0x10B72, 54),
+    (0x10B78, 0x10B7F, 54),
+    (0x10B80, 0x10B91, 119),
+    (0x10B99, 0x10B9C, 119),
+    (0x10BA9, 0x10BAF, 119),
+    (0x10C00, 0x10C48, 109),
+    (0x10C80, 0x10CB2, 102),
+    (0x10CC0, 0x10CF2, 102),
+    (0x10CFA, 0x10CFF, 102),
+    (0x10D00, 0x10D27, 48),
+    (0x10D30, 0x10D39, 48),
+    (0x10E60, 0x10E7E, 3),
+    (0x10E80, 0x10EA9, 156),
+    (0x10EAB, 0x10EAD, 156),
+    (0x10EB0, 0x10EB1, 156),
+    (0x10F00, 0x10F27, 107),
+    (0x10F30, 0x10F59, 129),
+    (0x10F70, 0x10F89, 110),
+    (0x10FB0, 0x10FCB, 23),
+    (0x10FE0, 0x10FF6, 36),
+    (0x11000, 0x1104D, 13),
+    (0x11052, 0x11075, 13),
+    (0x1107F, 0x1107F, 13),
+    (0x11080, 0x110C2, 57),
+    (0x110CD, 0x110CD, 57),
+    (0x110D0, 0x110E8, 130),
+    (0x110F0, 0x110F9, 130),
+    (0x11100, 0x11134, 20),
+    (0x11136, 0x11147, 20),
+    (0x11150, 0x11176, 75),
+    (0x11180, 0x111DF, 124),
+    (0x111E1, 0x111F4, 128),
+    (0x11200, 0x11211, 64),
+    (0x11213, 0x1123E, 64),
+    (0x11280, 0x11286, 91),
+    (0x11288, 0x11288, 91),
+    (0x1128A, 0x1128D, 91),
+    (0x1128F, 0x1129D, 91),
+    (0x1129F, 0x112A9, 91),
+    (0x112B0, 0x112EA, 65),
+    (0x112F0, 0x112F9, 65),
+    (0x11300, 0x11303, 41),
+    (0x11305, 0x1130C, 41),
+    (0x1130F, 0x11310, 41),
+    (0x11313, 0x11328, 41),
+    (0x1132A, 0x11330, 41),
+    (0x11332, 0x11333, 41),
+    (0x11335, 0x11339, 41),
+    (0x1133C, 0x11344, 41),
+    (0x11347, 0x11348, 41),
+    (0x1134B, 0x1134D, 41),
+    (0x11350, 0x11350, 41),
+    (0x11357, 0x11357, 41),
+    (0x1135D, 0x11363, 41),
+    (0x11366, 0x1136C, 41),
+    (0x11370, 0x11374, 41),
+    (0x11400, 0x1145B, 96),
+    (0x1145D, 0x11461, 96),
+    (0x11480, 0x114C7, 149),
+    (0x114D0, 0x114D9, 149),
+    (0x11580, 0x115B5, 126),
+    (0x115B8, 0x115DD, 126),
+    (0x11600, 0x11644, 88),
+    (0x11650, 0x11659, 88),
+    (0x11660, 0x1166C, 89),
+    (0x11680, 0x116B9, 140),
+    (0x116C0, 0x116C9, 140),
+    (0x11700, 0x1171A, 1),
+    (0x1171D, 0x1172B, 1),
+    (0x11730, 0x11746, 1),
+    (0x11800, 0x1183B, 32),
+    (0x118A0, 0x118F2, 155),
+    (0x118FF, 0x118FF, 155),
+    (0x11900, 0x11906, 31),
+    (0x11909, 0x11909, 31),
+    (0x1190C, 0x11913, 31),
+    (0x11915, 0x11916, 31),
+    (0x11918, 0x11935, 31),
+    (0x11937, 0x11938, 31),
+    (0x1193B, 0x11946, 31),
+    (0x11950, 0x11959, 31),
+    (0x119A0, 0x119A7, 94),
+    (0x119AA, 0x119D7, 94),
+    (0x119DA, 0x119E4, 94),
+    (0x11A00, 0x11A47, 158),
+    (0x11A50, 0x11AA2, 131),
+    (0x11AB0, 0x11ABF, 17),
+    (0x11AC0, 0x11AF8, 116),
+    (0x11C00, 0x11C08, 11),
+    (0x11C0A, 0x11C36, 11),
+    (0x11C38, 0x11C45, 11),
+    (0x11C50, 0x11C6C, 11),
+    (0x11C70, 0x11C8F, 80),
+    (0x11C92, 0x11CA7, 80),
+    (0x11CA9, 0x11CB6, 80),
+    (0x11D00, 0x11D06, 81),
+    (0x11D08, 0x11D09, 81),
+    (0x11D0B, 0x11D36, 81),
+    (0x11D3A, 0x11D3A, 81),
+    (0x11D3C, 0x11D3D, 81),
+    (0x11D3F, 0x11D47, 81),
+    (0x11D50, 0x11D59, 81),
+    (0x11D60, 0x11D65, 44),
+    (0x11D67, 0x11D68, 44),
+    (0x11D6A, 0x11D8E, 44),
+    (0x11D90, 0x11D91, 44),
+    (0x11D93, 0x11D98, 44),
+    (0x11DA0, 0x11DA9, 44),
+    (0x11EE0, 0x11EF8, 76),
+    (0x11FB0, 0x11FB0, 72),
+    (0x11FC0, 0x11FF1, 141),
+    (0x11FFF, 0x11FFF, 141),
+    (0x12000, 0x12399, 25),
+    (0x12400, 0x1246E, 25),
+    (0x12470, 0x12474, 25),
+    (0x12480, 0x12543, 25),
+    (0x12F90, 0x12FF2, 27),
+    (0x13000, 0x1342E, 34),
+    (0x13430, 0x13438, 34),
+    (0x14400, 0x14646, 2),
+    (0x16800, 0x16A38, 7),
+    (0x16A40, 0x16A5E, 90),
+    (0x16A60, 0x16A69, 90),
+    (0x16A6E, 0x16A6F, 90),
+    (0x16A70, 0x16ABE, 142),
+    (0x16AC0, 0x16AC9, 142),
+    (0x16AD0, 0x16AED, 8),
+    (0x16AF0, 0x16AF5, 8),
+    (0x16B00, 0x16B45, 114),
+    (0x16B50, 0x16B59, 114),
+    (0x16B5B, 0x16B61, 114),
+    (0x16B63, 0x16B77, 114),
+    (0x16B7D, 0x16B8F, 114),
+    (0x16E40, 0x16E9A, 82),
+    (0x16F00, 0x16F4A, 87),
+    (0x16F4F, 0x16F87, 87),
+    (0x16F8F, 0x16F9F, 87),
+    (0x16FE0, 0x16FE0, 143),
+    (0x16FE1, 0x16FE1, 98),
+    (0x16FE2, 0x16FE3, 46),
+    (0x16FE4, 0x16FE4, 62),
+    (0x16FF0, 0x16FF1, 46),
+    (0x17000, 0x187F7, 143),
+    (0x18800, 0x18AFF, 143),
+    (0x18B00, 0x18CD5, 62),
+    (0x18D00, 0x18D08, 143),
+    (0x1AFF0, 0x1AFF3, 59),
+    (0x1AFF5, 0x1AFFB, 59),
+    (0x1AFFD, 0x1AFFE, 59),
+    (0x1B000, 0x1B000, 59),
+    (0x1B001, 0x1B11F, 52),
+    (0x1B120, 0x1B122, 59),
+    (0x1B150, 0x1B152, 52),
+    (0x1B164, 0x1B167, 59),
+    (0x1B170, 0x1B2FB, 98),
+    (0x1BC00, 0x1BC6A, 33),
+    (0x1BC70, 0x1BC7C, 33),
+    (0x1BC80, 0x1BC88, 33),
+    (0x1BC90, 0x1BC99, 33),
+    (0x1BC9C, 0x1BC9F, 33),
+    (0x1D200, 0x1D245, 42),
+    (0x1D800, 0x1DA8B, 127),
+    (0x1DA9B, 0x1DA9F, 127),
+    (0x1DAA1, 0x1DAAF, 127),
+    (0x1DF00, 0x1DF1E, 67),
+    (0x1E000, 0x1E006, 39),
+    (0x1E008, 0x1E018, 39),
+    (0x1E01B, 0x1E021, 39),
+    (0x1E023, 0x1E024, 39),
+    (0x1E026, 0x1E02A, 39),
+    (0x1E100, 0x1E12C, 99),
+    (0x1E130, 0x1E13D, 99),
+    (0x1E140, 0x1E149, 99),
+    (0x1E14E, 0x1E14F, 99),
+    (0x1E290, 0x1E2AE, 150),
+    (0x1E2C0, 0x1E2F9, 154),
+    (0x1E2FF, 0x1E2FF, 154),
+    (0x1E7E0, 0x1E7E6, 37),
+    (0x1E7E8, 0x1E7EB, 37),
+    (0x1E7ED, 0x1E7EE, 37),
+    (0x1E7F0, 0x1E7FE, 37),
+    (0x1E800, 0x1E8C4, 84),
+    (0x1E8C7, 0x1E8D6, 84),
+    (0x1E900, 0x1E94B, 0),
+    (0x1E950, 0x1E959, 0),
+    (0x1E95E, 0x1E95F, 0),
+    (0x1EE00, 0x1EE03, 3),
+    (0x1EE05, 0x1EE1F, 3),
+    (0x1EE21, 0x1EE22, 3),
+    (0x1EE24, 0x1EE24, 3),
+    (0x1EE27, 0x1EE27, 3),
+    (0x1EE29, 0x1EE32, 3),
+    (0x1EE34, 0x1EE37, 3),
+    (0x1EE39, 0x1EE39, 3),
+    (0x1EE3B, 0x1EE3B, 3),
+    (0x1EE42, 0x1EE42, 3),
+    (0x1EE47, 0x1EE47, 3),
+    (0x1EE49, 0x1EE49, 3),
+    (0x1EE4B, 0x1EE4B, 3),
+    (0x1EE4D, 0x1EE4F, 3),
+    (0x1EE51, 0x1EE52, 3),
+    (0x1EE54, 0x1EE54, 3),
+    (0x1EE57, 0x1EE57, 3),
+    (0x1EE59, 0x1EE59, 3),
+    (0x1EE5B, 0x1EE5B, 3),
+    (0x1EE5D, 0x1EE5D, 3),
+    (0x1EE5F, 0x1EE5F, 3),
+    (0x1EE61, 0x1EE62, 3),
+    (0x1EE64, 0x1EE64, 3),
+    (0x1EE67, 0x1EE6A, 3),
+    (0x1EE6C, 0x1EE72, 3),
+    (0x1EE74, 0x1EE77, 3),
+    (0x1EE79, 0x1EE7C, 3),
+    (0x1EE7E, 0x1EE7E, 3),
+    (0x1EE80, 0x1EE89, 3),
+    (0x1EE8B, 0x1EE9B, 3),
+    (0x1EEA1, 0x1EEA3, 3),
+    (0x1EEA5, 0x1EEA9, 3),
+    (0x1EEAB, 0x1EEBB, 3),
+    (0x1EEF0, 0x1EEF1, 3),
+    (0x1F200, 0x1F200, 52),
+    (0x20000, 0x2A6DF, 46),
+    (0x2A700, 0x2B738, 46),
+    (0x2B740, 0x2B81D, 46),
+    (0x2B820, 0x2CEA1, 46),
+    (0x2CEB0, 0x2EBE0, 46),
+    (0x2F800, 0x2FA1D, 46),
+    (0x30000, 0x3134A, 46),
+];
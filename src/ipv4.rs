@@ -1,7 +1,8 @@
 /// IPv4 address parser supporting decimal, octal, and hexadecimal notation
 /// Based on WHATWG URL specification
-use crate::compat::{String, Vec, format};
+use crate::compat::{String, format};
 use crate::error::{ParseError, Result};
+use core::net::Ipv4Addr;
 
 /// Parse an IPv4 address string into a u32.
 /// Supports:
@@ -9,6 +10,19 @@ use crate::error::{ParseError, Result};
 /// - Hex: 0xC0A80101
 /// - Octal: 0300.0250.01.01
 /// - Mixed: 192.0x00A80001
+///
+/// # Examples
+///
+/// ```
+/// use ars::{parse_ipv4, serialize_ipv4};
+///
+/// let addr = parse_ipv4("0177.0.0.1").unwrap();
+/// assert_eq!(serialize_ipv4(addr), "127.0.0.1");
+/// ```
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidIpv4` if the input is not a valid IPv4 address.
 pub fn parse_ipv4(input: &str) -> Result<u32> {
     if input.is_empty() {
         return Err(ParseError::InvalidIpv4);
@@ -17,24 +31,18 @@ pub fn parse_ipv4(input: &str) -> Result<u32> {
     // Remove trailing dot if present (WHATWG: trailing dot is allowed and ignored)
     let input = input.strip_suffix('.').unwrap_or(input);
 
-    // Split by dots and parse each part
-    let parts: Vec<&str> = input.split('.').collect();
-    let part_count = parts.len();
-
-    if part_count == 0 || part_count > 4 {
-        return Err(ParseError::InvalidIpv4);
+    // Split by dots and parse each part (at most 4, no allocation)
+    let mut numbers = [0u64; 4];
+    let mut part_count = 0;
+    for part in input.split('.') {
+        let slot = numbers.get_mut(part_count).ok_or(ParseError::InvalidIpv4)?;
+        if part.is_empty() {
+            return Err(ParseError::InvalidIpv4);
+        }
+        *slot = parse_ipv4_number(part)?;
+        part_count += 1;
     }
-
-    let numbers: Vec<u64> = parts
-        .iter()
-        .map(|part| {
-            if part.is_empty() {
-                Err(ParseError::InvalidIpv4)
-            } else {
-                parse_ipv4_number(part)
-            }
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let numbers = &numbers[..part_count];
 
     // Validate: last number must be < 256^(5-n)
     let last = numbers[part_count - 1];
@@ -65,6 +73,16 @@ pub fn parse_ipv4(input: &str) -> Result<u32> {
     Ok(ipv4)
 }
 
+/// Parse an IPv4 address string into a `core::net::Ipv4Addr`.
+/// Accepts the same notations as `parse_ipv4`.
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidIpv4` if the input is not a valid IPv4 address.
+pub fn parse_ipv4_addr(input: &str) -> Result<Ipv4Addr> {
+    parse_ipv4(input).map(Ipv4Addr::from)
+}
+
 /// Parse a single IPv4 number component (supports decimal, hex, octal).
 fn parse_ipv4_number(input: &str) -> Result<u64> {
     if input.is_empty() {
@@ -124,6 +142,28 @@ mod tests {
     #[test]
     fn test_parse_ipv4_octal() {
         assert_eq!(parse_ipv4("0300.0250.01.01").unwrap(), 0xC0A80101);
+        assert_eq!(
+            serialize_ipv4(parse_ipv4("0300.0250.00360").unwrap()),
+            "192.168.0.240"
+        );
+    }
+
+    #[test]
+    fn test_parse_ipv4_addr() {
+        assert_eq!(
+            parse_ipv4_addr("0300.0250.00360").unwrap(),
+            Ipv4Addr::new(192, 168, 0, 240)
+        );
+        assert_eq!(
+            parse_ipv4_addr("0xFF.0.0.1").unwrap(),
+            Ipv4Addr::new(255, 0, 0, 1)
+        );
+        assert_eq!(
+            serialize_ipv4(parse_ipv4("0xFF.0.0.1").unwrap()),
+            "255.0.0.1"
+        );
+        assert!(parse_ipv4_addr("0x100.0.0.1").is_err());
+        assert!(parse_ipv4_addr("1.2.3.4.5").is_err());
     }
 
     #[test]
@@ -1,8 +1,9 @@
 /// IPv6 address parsing and validation
 /// Implements WHATWG URL specification for IPv6 addresses
-use crate::compat::{String, Vec};
+use crate::compat::String;
 use crate::error::{ParseError, Result};
 use core::fmt::Write;
+use core::net::Ipv6Addr;
 
 /// Parse an IPv6 address from bracket notation (e.g., "[`::1`]" or "[`2001:db8::1`]").
 /// Returns the 8 u16 segments if valid, or an error if malformed.
@@ -30,42 +31,20 @@ pub fn parse_ipv6(input: &str) -> Result<[u16; 8]> {
     }
 }
 
+/// Parse an IPv6 address string into a `core::net::Ipv6Addr`.
+/// Accepts the same notations as `parse_ipv6`, with or without brackets.
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidIpv6` if the input is not a valid IPv6 address.
+pub fn parse_ipv6_addr(input: &str) -> Result<Ipv6Addr> {
+    parse_ipv6(input).map(Ipv6Addr::from)
+}
+
 /// Parse pure IPv6 address (no embedded IPv4).
 fn parse_ipv6_pure(input: &str) -> Result<[u16; 8]> {
     let mut segments = [0u16; 8];
-
-    let Some(double_colon_pos) = input.find("::") else {
-        // No :: compression - must have exactly 8 segments
-        let parsed = parse_segments(input)?;
-        if parsed.len() != 8 {
-            return Err(ParseError::InvalidIpv6);
-        }
-        segments.copy_from_slice(&parsed);
-        return Ok(segments);
-    };
-
-    // Split around :: and parse both parts
-    let before = &input[..double_colon_pos];
-    let after = &input[double_colon_pos + 2..];
-    let before_segments = parse_segments(before)?;
-    let after_segments = parse_segments(after)?;
-
-    // Check total segments
-    let total = before_segments.len() + after_segments.len();
-    if total > 7 {
-        return Err(ParseError::InvalidIpv6);
-    }
-
-    // Fill segments array
-    for (i, &seg) in before_segments.iter().enumerate() {
-        segments[i] = seg;
-    }
-
-    let after_start = before_segments.len() + (8 - total);
-    for (i, &seg) in after_segments.iter().enumerate() {
-        segments[after_start + i] = seg;
-    }
-
+    parse_pieces(input, &mut segments)?;
     Ok(segments)
 }
 
@@ -73,83 +52,99 @@ fn parse_ipv6_pure(input: &str) -> Result<[u16; 8]> {
 fn parse_ipv6_with_ipv4(input: &str) -> Result<[u16; 8]> {
     // Find the last : before the IPv4 part
     let last_colon = input.rfind(':').ok_or(ParseError::InvalidIpv6)?;
-    let ipv6_part = &input[..last_colon];
     let ipv4_part = &input[last_colon + 1..];
 
+    // The colon before the IPv4 part is a separator, unless it closes a `::`
+    let ipv6_part = if input[..=last_colon].ends_with("::") {
+        &input[..=last_colon]
+    } else {
+        &input[..last_colon]
+    };
+
     // Parse IPv4 address and convert to two u16 segments
     let ipv4_addr = parse_ipv4(ipv4_part)?;
     let ipv4_high = ((ipv4_addr >> 16) & 0xFFFF) as u16;
     let ipv4_low = (ipv4_addr & 0xFFFF) as u16;
 
     let mut segments = [0u16; 8];
+    parse_pieces(ipv6_part, &mut segments[..6])?;
+    segments[6] = ipv4_high;
+    segments[7] = ipv4_low;
 
-    if ipv6_part.is_empty() || ipv6_part == ":" {
-        segments[6] = ipv4_high;
-        segments[7] = ipv4_low;
-        return Ok(segments);
-    }
-
-    if let Some(double_colon_pos) = ipv6_part.find("::") {
-        let before = &ipv6_part[..double_colon_pos];
-        let after = &ipv6_part[double_colon_pos + 2..];
-        let before_segments = parse_segments(before)?;
-        let after_segments = parse_segments(after)?;
+    Ok(segments)
+}
 
-        let total = before_segments.len() + after_segments.len();
-        if total > 6 {
+/// Parse colon-separated hex pieces filling all of `out`.
+/// Without `::` the piece count must match exactly; with `::` it must
+/// stand for at least one zero piece, as in the WHATWG IPv6 parser.
+fn parse_pieces(s: &str, out: &mut [u16]) -> Result<()> {
+    let Some(double_colon_pos) = s.find("::") else {
+        if parse_segments(s, out)? != out.len() {
             return Err(ParseError::InvalidIpv6);
         }
+        return Ok(());
+    };
 
-        for (i, &seg) in before_segments.iter().enumerate() {
-            segments[i] = seg;
-        }
+    // Split around :: and parse both parts
+    let before = &s[..double_colon_pos];
+    let after = &s[double_colon_pos + 2..];
+    let mut after_segments = [0u16; 8];
+    let before_len = parse_segments(before, out)?;
+    let after_len = parse_segments(after, &mut after_segments[..out.len()])?;
 
-        let after_start = before_segments.len() + (6 - total);
-        for (i, &seg) in after_segments.iter().enumerate() {
-            segments[after_start + i] = seg;
-        }
-    } else {
-        // No :: compression - must have exactly 6 segments
-        let parsed = parse_segments(ipv6_part)?;
-        if parsed.len() != 6 {
-            return Err(ParseError::InvalidIpv6);
-        }
-        segments[..6].copy_from_slice(&parsed);
+    if before_len + after_len >= out.len() {
+        return Err(ParseError::InvalidIpv6);
     }
 
-    segments[6] = ipv4_high;
-    segments[7] = ipv4_low;
+    // Move the trailing segments to the end, leaving zeros in between
+    let len = out.len();
+    out[len - after_len..].copy_from_slice(&after_segments[..after_len]);
 
-    Ok(segments)
+    Ok(())
 }
 
 /// Parse a single hex segment (0-ffff).
 fn parse_hex_segment(s: &str) -> Result<u16> {
-    if s.is_empty() || s.len() > 4 {
+    if s.is_empty() || s.len() > 4 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
         return Err(ParseError::InvalidIpv6);
     }
     u16::from_str_radix(s, 16).map_err(|_| ParseError::InvalidIpv6)
 }
 
-/// Parse colon-separated hex segments from a string.
-fn parse_segments(s: &str) -> Result<Vec<u16>> {
+/// Parse colon-separated hex segments into `out` (no allocation).
+/// Returns the number of segments written, or an error if they don't fit.
+fn parse_segments(s: &str, out: &mut [u16]) -> Result<usize> {
     if s.is_empty() {
-        return Ok(Vec::new());
+        return Ok(0);
+    }
+    let mut count = 0;
+    for part in s.split(':') {
+        let slot = out.get_mut(count).ok_or(ParseError::InvalidIpv6)?;
+        *slot = parse_hex_segment(part)?;
+        count += 1;
     }
-    s.split(':').map(parse_hex_segment).collect()
+    Ok(count)
 }
 
 /// Parse an IPv4 address to u32.
 fn parse_ipv4(s: &str) -> Result<u32> {
-    let parts: Vec<&str> = s.split('.').collect();
-    if parts.len() != 4 {
-        return Err(ParseError::InvalidIpv4);
-    }
-
-    parts.iter().try_fold(0u32, |acc, part| {
+    let mut count = 0;
+    let value = s.split('.').try_fold(0u32, |acc, part| {
+        count += 1;
+        // Decimal digits only, without leading zeros
+        if part.is_empty()
+            || (part.len() > 1 && part.starts_with('0'))
+            || !part.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(ParseError::InvalidIpv4);
+        }
         let byte: u8 = part.parse().map_err(|_| ParseError::InvalidIpv4)?;
         Ok((acc << 8) | u32::from(byte))
-    })
+    })?;
+    if count != 4 {
+        return Err(ParseError::InvalidIpv4);
+    }
+    Ok(value)
 }
 
 /// Serialize IPv6 segments to string with compression.
@@ -265,4 +260,74 @@ mod tests {
             "[::7f00:1]"
         );
     }
+
+    #[test]
+    fn test_parse_ipv6_piece_count() {
+        assert_eq!(
+            parse_ipv6("[1:2:3:4:5:6:7:8]").unwrap(),
+            [1, 2, 3, 4, 5, 6, 7, 8]
+        );
+        assert_eq!(
+            parse_ipv6("[::1:2:3:4:5:6:7]").unwrap(),
+            [0, 1, 2, 3, 4, 5, 6, 7]
+        );
+        assert!(parse_ipv6("[1:2:3:4:5:6:7:8:9]").is_err());
+        assert!(parse_ipv6("[1:2:3:4:5:6:7]").is_err());
+        // `::` must stand for at least one zero piece
+        assert!(parse_ipv6("[::1:2:3:4:5:6:7:8]").is_err());
+        assert!(parse_ipv6("[1:2:3:4::5:6:7:8]").is_err());
+    }
+
+    #[test]
+    fn test_parse_ipv6_stray_colons() {
+        assert!(parse_ipv6("[1::2::3]").is_err());
+        assert!(parse_ipv6("[:1]").is_err());
+        assert!(parse_ipv6("[1:]").is_err());
+        assert!(parse_ipv6("[:1::]").is_err());
+        assert!(parse_ipv6("[::1:]").is_err());
+        assert!(parse_ipv6("[1:::2]").is_err());
+        assert!(parse_ipv6("[+1::]").is_err());
+    }
+
+    #[test]
+    fn test_parse_ipv6_with_ipv4_edge_cases() {
+        assert_eq!(
+            parse_ipv6("[1::1.2.3.4]").unwrap(),
+            [1, 0, 0, 0, 0, 0, 0x0102, 0x0304]
+        );
+        assert_eq!(
+            parse_ipv6("[1:2:3:4:5:6:1.2.3.4]").unwrap(),
+            [1, 2, 3, 4, 5, 6, 0x0102, 0x0304]
+        );
+        assert!(parse_ipv6("[:1.2.3.4]").is_err());
+        assert!(parse_ipv6("[1:::1.2.3.4]").is_err());
+        assert!(parse_ipv6("[1:2:3::4:5:6:1.2.3.4]").is_err());
+        assert!(parse_ipv6("[::01.2.3.4]").is_err());
+        assert!(parse_ipv6("[::+1.2.3.4]").is_err());
+    }
+
+    #[test]
+    fn test_serialize_ipv6_zero_runs() {
+        assert_eq!(serialize_ipv6(&[0; 8]), "[::]");
+        assert_eq!(
+            serialize_ipv6(&parse_ipv6("[0:0:0:0:0:0:0:0]").unwrap()),
+            "[::]"
+        );
+        assert_eq!(serialize_ipv6(&[1, 0, 0, 0, 0, 0, 0, 1]), "[1::1]");
+        // Isolated zeros are never compressed.
+        assert_eq!(
+            serialize_ipv6(&[0, 1, 0, 1, 0, 1, 0, 1]),
+            "[0:1:0:1:0:1:0:1]"
+        );
+    }
+
+    #[test]
+    fn test_parse_ipv6_addr() {
+        assert_eq!(
+            parse_ipv6_addr("[2001:db8::1]").unwrap(),
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)
+        );
+        assert_eq!(parse_ipv6_addr("::1").unwrap(), Ipv6Addr::LOCALHOST);
+        assert!(parse_ipv6_addr("[1::2::3]").is_err());
+    }
 }
@@ -7,27 +7,52 @@ extern crate alloc;
 mod compat;
 
 // Internal modules (not public API)
+mod base_url;
 mod character_sets;
 mod checkers;
+mod data_url;
 mod error;
+#[cfg(feature = "std")]
+mod file_path;
 mod helpers;
 mod idna;
 mod ipv4;
 mod ipv6;
+mod mailto;
+mod parse_options;
 mod parser;
 mod scheme;
 mod types;
 mod unicode;
 mod url_aggregator;
 mod url_base;
+mod url_builder;
 #[doc(hidden)]
 pub use url_base::UrlBase;
 mod url_components;
+mod url_parser;
+mod url_parts;
+mod url_ranges;
 mod url_search_params;
 
 // Public API
-pub use error::ParseError;
+pub use base_url::BaseUrl;
+pub use checkers::is_valid_host;
+pub use data_url::DataUrl;
+pub use error::{ParseError, SetterError};
+pub use ipv4::{parse_ipv4, parse_ipv4_addr, serialize_ipv4};
+pub use ipv6::parse_ipv6_addr;
+pub use mailto::MailtoParts;
+pub use parse_options::ParseOptions;
+pub use parser::{parse_all, parse_all_valid, parse_host, parse_lines};
+pub use types::{IpClass, SchemeType};
+pub use unicode::idna::{IdnaOptions, domain_to_ascii_with_options};
+pub use unicode::percent_encode::{EncodeSet, encode_with_set, percent_decode, percent_decode_cow};
 pub use url_aggregator::UrlAggregator as Url;
+pub use url_builder::UrlBuilder;
+pub use url_parser::UrlParser;
+pub use url_parts::UrlParts;
+pub use url_ranges::UrlRanges;
 pub use url_search_params::UrlSearchParams;
 
 pub type Result<T> = core::result::Result<T, ParseError>;
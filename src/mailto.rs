@@ -0,0 +1,60 @@
+use crate::compat::{String, ToString, Vec};
+use crate::unicode::percent_encode::percent_decode_lossy;
+use crate::url_search_params::split_pairs;
+
+/// Recipients and headers of a `mailto:` URL, produced by `Url::mailto_parts`
+///
+/// Follows RFC 6068: `mailto:addr1,addr2?header=value&...`. Everything is
+/// percent-decoded; '+' is a literal plus sign, not a space.
+///
+/// - `addresses`: recipients from the path, in order
+/// - `headers`: query pairs in order, e.g. `("subject", "Hi")`; a `to`
+///   header is kept here rather than merged into `addresses`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MailtoParts {
+    pub addresses: Vec<String>,
+    pub headers: Vec<(String, String)>,
+}
+
+impl MailtoParts {
+    /// Split the path and query of a `mailto:` URL
+    pub(crate) fn parse(path: &str, query: &str) -> Self {
+        let addresses = path
+            .split(',')
+            .map(|address| percent_decode_lossy(address).trim().to_string())
+            .filter(|address| !address.is_empty())
+            .collect();
+        let headers = split_pairs(query)
+            .map(|(name, value)| {
+                (
+                    percent_decode_lossy(name).into_owned(),
+                    percent_decode_lossy(value).into_owned(),
+                )
+            })
+            .collect();
+        Self { addresses, headers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mailto() {
+        let parts = MailtoParts::parse("a@x.com,%20b@y.com,", "?subject=Hello%20there&body=1+1");
+        assert_eq!(parts.addresses, ["a@x.com", "b@y.com"]);
+        assert_eq!(
+            parts.headers,
+            [
+                (String::from("subject"), String::from("Hello there")),
+                (String::from("body"), String::from("1+1")),
+            ]
+        );
+
+        let parts = MailtoParts::parse("", "?to=a@x.com");
+        assert!(parts.addresses.is_empty());
+        assert_eq!(parts.headers.len(), 1);
+        assert_eq!(MailtoParts::parse("", ""), MailtoParts::default());
+    }
+}
@@ -17,6 +17,28 @@ pub fn get_scheme_type(scheme: &str) -> SchemeType {
     }
 }
 
+/// Check that a scheme starts with an ASCII letter followed only by ASCII
+/// alphanumerics, '+', '-' or '.'
+pub fn is_valid_scheme(scheme: &str) -> bool {
+    let mut bytes = scheme.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+/// Get the scheme type, treating the schemes in `special_schemes` as special
+/// The WHATWG special schemes keep their own type even if registered again
+pub fn get_scheme_type_with(scheme: &str, special_schemes: &[(&str, Option<u16>)]) -> SchemeType {
+    match get_scheme_type(scheme) {
+        SchemeType::NotSpecial => special_schemes
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(scheme))
+            .map_or(SchemeType::NotSpecial, |&(_, port)| {
+                SchemeType::Custom(port)
+            }),
+        scheme_type => scheme_type,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -28,4 +50,29 @@ mod tests {
         assert_eq!(get_scheme_type("ftp"), SchemeType::Ftp);
         assert_eq!(get_scheme_type("custom"), SchemeType::NotSpecial);
     }
+
+    #[test]
+    fn test_is_valid_scheme() {
+        for scheme in ["http", "a", "git+ssh", "x-y.z", "H2"] {
+            assert!(is_valid_scheme(scheme), "{scheme}");
+        }
+        for scheme in ["", "1http", "+a", "ht tp", "ht/tp", "é"] {
+            assert!(!is_valid_scheme(scheme), "{scheme}");
+        }
+    }
+
+    #[test]
+    fn test_scheme_type_with_registered() {
+        let schemes = [("app", Some(9000)), ("http", Some(1))];
+        assert_eq!(
+            get_scheme_type_with("app", &schemes),
+            SchemeType::Custom(Some(9000))
+        );
+        assert_eq!(get_scheme_type_with("http", &schemes), SchemeType::Http);
+        assert_eq!(
+            get_scheme_type_with("custom", &schemes),
+            SchemeType::NotSpecial
+        );
+        assert_eq!(get_scheme_type_with("app", &[]), SchemeType::NotSpecial);
+    }
 }
@@ -1,4 +1,18 @@
 /// URL scheme types
+///
+/// Every scheme other than the special ones is `NotSpecial`.
+///
+/// # Examples
+///
+/// ```
+/// use ars::{SchemeType, Url};
+///
+/// assert_eq!(SchemeType::Https.default_port(), Some(443));
+/// assert!(!SchemeType::NotSpecial.is_special());
+///
+/// let url = Url::parse("ws://example.com/", None).unwrap();
+/// assert_eq!(url.scheme_type(), SchemeType::Ws);
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SchemeType {
     #[default]
@@ -8,6 +22,9 @@ pub enum SchemeType {
     Wss,
     Ftp,
     File,
+    /// A scheme registered as special with `ParseOptions::special_schemes`,
+    /// with its default port
+    Custom(Option<u16>),
     NotSpecial,
 }
 
@@ -23,7 +40,83 @@ impl SchemeType {
             Self::Http | Self::Ws => Some(80),
             Self::Https | Self::Wss => Some(443),
             Self::Ftp => Some(21),
+            Self::Custom(port) => port,
             Self::File | Self::NotSpecial => None,
         }
     }
 }
+
+/// Address class of an IP literal host, for SSRF checks
+///
+/// Produced by `Url::host_ip_class`. IPv4-mapped IPv6 addresses
+/// (`::ffff:a.b.c.d`) are classified by their IPv4 address.
+///
+/// # Examples
+///
+/// ```
+/// use ars::{IpClass, Url};
+///
+/// let url = Url::parse("http://192.168.0.1/", None).unwrap();
+/// assert_eq!(url.host_ip_class(), Some(IpClass::Private));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpClass {
+    /// 127.0.0.0/8 and `::1`
+    Loopback,
+    /// 10.0.0.0/8, 172.16.0.0/12, 192.168.0.0/16 and `fc00::/7`
+    Private,
+    /// 169.254.0.0/16 and `fe80::/10`
+    LinkLocal,
+    /// Publicly routable unicast addresses
+    Global,
+    /// Unspecified, broadcast, multicast, shared (100.64.0.0/10),
+    /// documentation, benchmarking and reserved addresses
+    Other,
+}
+
+impl IpClass {
+    /// Classify an IP address
+    pub fn of(addr: core::net::IpAddr) -> Self {
+        match addr {
+            core::net::IpAddr::V4(addr) => Self::of_ipv4(addr),
+            core::net::IpAddr::V6(addr) => Self::of_ipv6(addr),
+        }
+    }
+
+    fn of_ipv4(addr: core::net::Ipv4Addr) -> Self {
+        let [a, b, c, _] = addr.octets();
+        match (a, b, c) {
+            (127, _, _) => Self::Loopback,
+            (10, _, _) | (172, 16..=31, _) | (192, 168, _) => Self::Private,
+            (169, 254, _) => Self::LinkLocal,
+            (0 | 224..=255, _, _)
+            | (100, 64..=127, _)
+            | (192, 0, 0 | 2)
+            | (198, 18..=19, _)
+            | (198, 51, 100)
+            | (203, 0, 113) => Self::Other,
+            _ => Self::Global,
+        }
+    }
+
+    fn of_ipv6(addr: core::net::Ipv6Addr) -> Self {
+        if let Some(v4) = addr.to_ipv4_mapped() {
+            return Self::of_ipv4(v4);
+        }
+        let segments = addr.segments();
+        if addr.is_loopback() {
+            Self::Loopback
+        } else if segments[0] & 0xfe00 == 0xfc00 {
+            Self::Private
+        } else if segments[0] & 0xffc0 == 0xfe80 {
+            Self::LinkLocal
+        } else if addr.is_unspecified()
+            || segments[0] & 0xff00 == 0xff00
+            || (segments[0] == 0x2001 && segments[1] == 0x0db8)
+        {
+            Self::Other
+        } else {
+            Self::Global
+        }
+    }
+}
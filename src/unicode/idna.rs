@@ -1,6 +1,11 @@
 use crate::compat::String;
 use crate::error::{ParseError, Result};
 use crate::helpers::find_byte;
+use crate::idna::script_tables::{
+    SCRIPT, SCRIPT_BOPOMOFO, SCRIPT_HAN, SCRIPT_HANGUL, SCRIPT_HIRAGANA, SCRIPT_KATAKANA,
+};
+use crate::unicode::is_forbidden_domain_code_point;
+use core::cmp::Ordering;
 
 /// Check if 4 bytes match "xn--" (case insensitive)
 fn is_punycode_prefix(slice: &[u8]) -> bool {
@@ -36,6 +41,12 @@ pub fn has_punycode(domain: &str) -> bool {
 }
 
 /// Process a domain using IDNA `ToASCII` algorithm
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidHost` if the domain has a forbidden code
+/// point, a label starting with a combining mark, or breaks the Bidi rule,
+/// and `ParseError::IdnaError` if IDNA processing fails otherwise.
 pub fn domain_to_ascii(domain: &str) -> Result<String> {
     // Fast path: Pure ASCII without percent-encoding or Punycode
     // Most common case - avoid expensive IDNA processing
@@ -45,19 +56,180 @@ pub fn domain_to_ascii(domain: &str) -> Result<String> {
         let mut result = String::with_capacity(domain.len());
 
         for b in domain.bytes() {
-            match b {
-                // Valid hostname chars: a-z, A-Z, 0-9, ., -
-                b'A'..=b'Z' => result.push((b + 32) as char), // Lowercase
-                b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' => result.push(b as char),
-                _ => return Err(ParseError::InvalidHost),
+            // Same rule as the slow path: only forbidden domain code points are rejected
+            if is_forbidden_domain_code_point(b) {
+                return Err(ParseError::InvalidHost);
             }
+            result.push(b.to_ascii_lowercase() as char);
         }
 
         return Ok(result);
     }
 
     // Slow path: Unicode, percent-encoded, or Punycode - use full IDNA processing
-    crate::idna::domain_to_ascii(domain).ok_or(ParseError::IdnaError)
+    crate::idna::domain_to_ascii(domain)
+}
+
+/// Options for `domain_to_ascii_with_options`
+///
+/// The defaults match URL parsing, which always uses UTS #46
+/// non-transitional processing as the WHATWG URL Standard requires.
+///
+/// # Examples
+///
+/// ```
+/// use ars::{IdnaOptions, domain_to_ascii_with_options};
+///
+/// let options = IdnaOptions::new();
+/// assert_eq!(domain_to_ascii_with_options("faß.de", &options).unwrap(), "xn--fa-hia.de");
+///
+/// let options = IdnaOptions::new().transitional(true);
+/// assert_eq!(domain_to_ascii_with_options("faß.de", &options).unwrap(), "fass.de");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdnaOptions {
+    pub(crate) transitional: bool,
+    pub(crate) use_std3_ascii_rules: bool,
+    pub(crate) verify_dns_length: bool,
+}
+
+impl IdnaOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map the deviation characters (ß → ss, ς → σ, ZWJ/ZWNJ removed) as in
+    /// transitional processing, instead of keeping them (default)
+    #[must_use]
+    pub fn transitional(mut self, transitional: bool) -> Self {
+        self.transitional = transitional;
+        self
+    }
+
+    /// Restrict labels to ASCII letters, digits and '-' (STD3 rules),
+    /// instead of also allowing characters such as '_' (default, as in WHATWG)
+    #[must_use]
+    pub fn use_std3_ascii_rules(mut self, strict: bool) -> Self {
+        self.use_std3_ascii_rules = strict;
+        self
+    }
+
+    /// Require labels of 1 to 63 octets and a domain of at most 253 octets
+    /// after conversion (UTS 46 `VerifyDnsLength`), instead of allowing any
+    /// length (default, as in WHATWG)
+    #[must_use]
+    pub fn verify_dns_length(mut self, verify: bool) -> Self {
+        self.verify_dns_length = verify;
+        self
+    }
+}
+
+/// Check that an ASCII domain only uses letters, digits, '-' and '.'
+pub(crate) fn is_std3_ascii(domain: &str) -> bool {
+    domain
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'.')
+}
+
+/// Check the DNS length limits of an ASCII domain: 1 to 63 octets per
+/// label and at most 253 octets in total, ignoring a trailing root '.'
+pub(crate) fn has_valid_dns_length(domain: &str) -> bool {
+    let domain = domain.strip_suffix('.').unwrap_or(domain);
+    !domain.is_empty()
+        && domain.len() <= 253
+        && domain
+            .split('.')
+            .all(|label| !label.is_empty() && label.len() <= 63)
+}
+
+/// Script of a code point, from the Unicode `Script` property
+///
+/// Returns `None` for Common and Inherited code points such as digits, '-'
+/// and combining marks, which go with any script.
+fn script(cp: u32) -> Option<u8> {
+    SCRIPT
+        .binary_search_by(|&(first, last, _)| {
+            if last < cp {
+                Ordering::Less
+            } else if first > cp {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|index| SCRIPT[index].2)
+}
+
+// Augmented script sets (UTS #39): Han also counts as Japanese, Korean and
+// Han-with-Bopomofo, so the usual CJK combinations stay single-script
+const JAPANESE: u8 = 1;
+const KOREAN: u8 = 1 << 1;
+const HAN_BOPOMOFO: u8 = 1 << 2;
+
+/// Augmented script set of a CJK script, or `None` for other scripts
+fn cjk_set(script: u8) -> Option<u8> {
+    match script {
+        SCRIPT_HAN => Some(JAPANESE | KOREAN | HAN_BOPOMOFO),
+        SCRIPT_HIRAGANA | SCRIPT_KATAKANA => Some(JAPANESE),
+        SCRIPT_HANGUL => Some(KOREAN),
+        SCRIPT_BOPOMOFO => Some(HAN_BOPOMOFO),
+        _ => None,
+    }
+}
+
+fn is_single_script_label(code_points: impl Iterator<Item = u32>) -> bool {
+    let mut other = None;
+    let mut cjk = JAPANESE | KOREAN | HAN_BOPOMOFO;
+    let mut has_cjk = false;
+    for script in code_points.filter_map(script) {
+        if let Some(set) = cjk_set(script) {
+            cjk &= set;
+            has_cjk = true;
+        } else if other
+            .replace(script)
+            .is_some_and(|previous| previous != script)
+        {
+            return false;
+        }
+    }
+    cjk != 0 && !(has_cjk && other.is_some())
+}
+
+/// Check that no label of a domain mixes scripts, e.g. Latin and Cyrillic
+///
+/// Punycode labels are decoded first, so the check sees the Unicode form.
+pub(crate) fn is_single_script(domain: &str) -> bool {
+    domain
+        .split('.')
+        .all(|label| match label.strip_prefix("xn--") {
+            Some(puny) => crate::idna::implementation::punycode_decode(puny)
+                .is_some_and(|decoded| is_single_script_label(decoded.into_iter())),
+            None => is_single_script_label(label.chars().map(u32::from)),
+        })
+}
+
+/// Process a domain using IDNA `ToASCII` with the given options
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidHost` or `ParseError::IdnaError` if the
+/// domain is not valid, or `ParseError::InvalidHost` if it breaks the
+/// STD3 rules or DNS length limits when they are enabled.
+pub fn domain_to_ascii_with_options(domain: &str, options: &IdnaOptions) -> Result<String> {
+    // Deviation characters are all non-ASCII
+    let ascii = if !options.transitional || domain.is_ascii() {
+        domain_to_ascii(domain)?
+    } else {
+        crate::idna::domain_to_ascii_with(domain, true)?
+    };
+    if options.use_std3_ascii_rules && !is_std3_ascii(&ascii) {
+        return Err(ParseError::InvalidHost);
+    }
+    if options.verify_dns_length && !has_valid_dns_length(&ascii) {
+        return Err(ParseError::InvalidHost);
+    }
+    Ok(ascii)
 }
 
 #[cfg(test)]
@@ -65,6 +237,78 @@ pub fn domain_to_ascii(domain: &str) -> Result<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_single_script() {
+        for domain in [
+            "example.com",
+            "bücher.de",
+            "пример.рф",
+            "例え.jp",
+            "한국어.kr",
+            "123",
+        ] {
+            assert!(
+                is_single_script(&domain_to_ascii(domain).unwrap()),
+                "{domain}"
+            );
+        }
+        // Latin "pa" + Cyrillic "у" + Latin "pal"
+        for domain in ["paуpal.com", "αpple.com", "xn--pypal-4ve.com"] {
+            assert!(
+                !is_single_script(&domain_to_ascii(domain).unwrap()),
+                "{domain}"
+            );
+        }
+        // Each label is checked on its own
+        assert!(is_single_script(&domain_to_ascii("пример.com").unwrap()));
+
+        // Greek + Coptic, Hangul + Hiragana
+        for domain in ["αϣ.com", "한あ.com"] {
+            assert!(
+                !is_single_script(&domain_to_ascii(domain).unwrap()),
+                "{domain}"
+            );
+        }
+        for domain in ["日本語カナ.jp", "中文ㄅ.tw", "한국語.kr"] {
+            assert!(
+                is_single_script(&domain_to_ascii(domain).unwrap()),
+                "{domain}"
+            );
+        }
+
+        // Labels longer than 63 code points
+        let long = format!("{}.de", "ü".repeat(70));
+        assert!(is_single_script(&domain_to_ascii(&long).unwrap()));
+    }
+
+    #[test]
+    fn test_verify_dns_length() {
+        let verify = IdnaOptions::new().verify_dns_length(true);
+        let label63 = "a".repeat(63);
+        let label64 = "a".repeat(64);
+
+        for domain in [label63.as_str(), "example.com.", "a"] {
+            assert!(
+                domain_to_ascii_with_options(domain, &verify).is_ok(),
+                "{domain}"
+            );
+        }
+        for domain in [label64.as_str(), "a..b", ""] {
+            assert_eq!(
+                domain_to_ascii_with_options(domain, &verify),
+                Err(ParseError::InvalidHost),
+                "{domain}"
+            );
+        }
+        // The limit applies to the Punycode form
+        let unicode = "aü".repeat(40);
+        assert!(domain_to_ascii(&unicode).unwrap().len() > 63);
+        assert!(domain_to_ascii_with_options(&unicode, &verify).is_err());
+
+        // Off by default
+        assert!(domain_to_ascii_with_options(&label64, &IdnaOptions::new()).is_ok());
+    }
+
     #[test]
     fn test_domain_to_ascii() {
         // ASCII domain should pass through
@@ -75,4 +319,136 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().starts_with("xn--"));
     }
+
+    #[test]
+    fn test_domain_to_ascii_bidi_rule() {
+        assert_eq!(
+            domain_to_ascii("مثال.إختبار").unwrap(),
+            "xn--mgbh0fb.xn--kgbechtv"
+        );
+        // RTL labels may end in a digit or trailing NSMs, and LTR labels
+        // are fine next to them
+        for domain in ["א1", "אְ", "مثال.com", "a1.מ", "xn--mgbh0fb"] {
+            assert!(domain_to_ascii(domain).is_ok(), "{domain}");
+        }
+        for domain in [
+            // L in an RTL label, or R/AL in an LTR label
+            "אa",
+            "aמ",
+            // Starts with a digit, in a Bidi domain name
+            "1א",
+            "0a.מ",
+            // EN and AN in one RTL label
+            "א1٢",
+            // RTL label ending in ON
+            "א-",
+            // Punycode labels are checked in decoded form ("aא")
+            "xn--a-0hc",
+        ] {
+            assert_eq!(
+                domain_to_ascii(domain),
+                Err(ParseError::InvalidHost),
+                "{domain}"
+            );
+        }
+        assert_eq!(
+            crate::url_aggregator::UrlAggregator::parse("http://אa/", None).unwrap_err(),
+            ParseError::InvalidHost
+        );
+        // Labels longer than 64 code points are checked too
+        let long_mixed = format!("a{}", "א".repeat(70));
+        assert!(domain_to_ascii(&long_mixed).is_err());
+        assert!(
+            crate::url_aggregator::UrlAggregator::parse(&format!("http://{long_mixed}/"), None)
+                .is_err()
+        );
+        assert!(domain_to_ascii(&"א".repeat(70)).is_ok());
+
+        // Outside Bidi domain names, labels may start with a digit
+        assert!(domain_to_ascii("0a.example").is_ok());
+        assert!(domain_to_ascii("0a.bücher").is_ok());
+    }
+
+    #[test]
+    fn test_domain_to_ascii_leading_combining_mark() {
+        // U+0301 COMBINING ACUTE ACCENT (Mn), U+0903 DEVANAGARI SIGN
+        // VISARGA (Mc, combining class 0), U+20DD COMBINING ENCLOSING
+        // CIRCLE (Me)
+        for domain in ["\u{301}a.com", "a.\u{903}\u{915}", "\u{20DD}a", "xn--a-wbb"] {
+            assert_eq!(
+                domain_to_ascii(domain),
+                Err(ParseError::InvalidHost),
+                "{domain}"
+            );
+        }
+        assert_eq!(
+            crate::url_aggregator::UrlAggregator::parse("http://\u{301}a.com/", None).unwrap_err(),
+            ParseError::InvalidHost
+        );
+
+        // Interior marks are fine; "e\u{301}" composes to "é" under NFC
+        assert_eq!(domain_to_ascii("e\u{301}x.com").unwrap(), "xn--x-9fa.com");
+        assert!(domain_to_ascii("\u{915}\u{903}.in").is_ok());
+    }
+
+    #[test]
+    fn test_domain_to_ascii_non_transitional_default() {
+        let options = IdnaOptions::new();
+        assert_eq!(domain_to_ascii("faß.de").unwrap(), "xn--fa-hia.de");
+        assert_eq!(
+            domain_to_ascii_with_options("faß.de", &options).unwrap(),
+            "xn--fa-hia.de"
+        );
+        assert_eq!(
+            domain_to_ascii_with_options("ς.gr", &options).unwrap(),
+            "xn--3xa.gr"
+        );
+
+        let url = crate::url_aggregator::UrlAggregator::parse("http://faß.de/", None).unwrap();
+        assert_eq!(url.hostname(), "xn--fa-hia.de");
+    }
+
+    #[test]
+    fn test_domain_to_ascii_transitional() {
+        let options = IdnaOptions::new().transitional(true);
+        assert_eq!(
+            domain_to_ascii_with_options("faß.de", &options).unwrap(),
+            "fass.de"
+        );
+        assert_eq!(
+            domain_to_ascii_with_options("FAẞ.de", &options).unwrap(),
+            "fass.de"
+        );
+        assert_eq!(
+            domain_to_ascii_with_options("ς.gr", &options).unwrap(),
+            "xn--4xa.gr"
+        );
+        assert_eq!(
+            domain_to_ascii_with_options("a\u{200D}b.de", &options).unwrap(),
+            "ab.de"
+        );
+        assert_eq!(
+            domain_to_ascii_with_options("日本.jp", &options).unwrap(),
+            domain_to_ascii("日本.jp").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_domain_to_ascii_std3_rules() {
+        let relaxed = IdnaOptions::new();
+        let strict = IdnaOptions::new().use_std3_ascii_rules(true);
+        assert_eq!(
+            domain_to_ascii_with_options("My_Host.local", &relaxed).unwrap(),
+            "my_host.local"
+        );
+        assert_eq!(
+            domain_to_ascii_with_options("My_Host.local", &strict).unwrap_err(),
+            ParseError::InvalidHost
+        );
+        assert_eq!(
+            domain_to_ascii_with_options("Bücher.example", &strict).unwrap(),
+            "xn--bcher-kva.example"
+        );
+        assert!(domain_to_ascii_with_options("a b.example", &relaxed).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::compat::{String, Vec};
+use crate::compat::{Cow, String, Vec};
 use crate::error::{ParseError, Result};
 
 #[derive(Clone, Copy)]
@@ -95,10 +95,16 @@ pub fn percent_encode_with_set(input: &str, encode_set: AsciiSet) -> String {
 /// Write percent-encoded string directly to buffer
 /// Manually iterates to avoid write! macro overhead
 pub fn percent_encode_into(buffer: &mut String, input: &str, encode_set: AsciiSet) {
+    percent_encode_bytes_into(buffer, input.as_bytes(), encode_set);
+}
+
+/// Write percent-encoded bytes directly to buffer
+/// Bytes outside ASCII are always encoded, so the input need not be UTF-8
+pub fn percent_encode_bytes_into(buffer: &mut String, input: &[u8], encode_set: AsciiSet) {
     // Reserve space to reduce reallocations
     buffer.reserve(input.len());
 
-    for &byte in input.as_bytes() {
+    for &byte in input {
         if byte >= 128 || encode_set.contains(byte) {
             buffer.push('%');
             buffer.push(hex_digit(byte >> 4));
@@ -140,6 +146,58 @@ pub const QUERY_SET: AsciiSet = C0_CONTROL_SET
     .add(b'<')
     .add(b'>');
 
+/// Public selector for the URL percent-encode sets
+///
+/// Every set encodes non-ASCII bytes (as UTF-8) and C0 controls and DEL;
+/// the variants differ in which printable ASCII characters are added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncodeSet {
+    /// Fragments: also space, `"`, `<`, `>` and `` ` ``
+    Fragment,
+    /// Queries of non-special URLs: also space, `"`, `#`, `<` and `>`
+    Query,
+    /// Queries of special URLs: `Query` plus `'`
+    SpecialQuery,
+    /// Paths: `Fragment` plus `#`, `?`, `^`, `{` and `}`
+    Path,
+    /// Opaque paths such as `mailto:` or `data:` paths: nothing beyond C0
+    /// controls, DEL and non-ASCII
+    OpaquePath,
+    /// Usernames and passwords: `Path` plus `/`, `:`, `;`, `=`, `@`, `[`,
+    /// `\`, `]` and `|`
+    Userinfo,
+}
+
+impl EncodeSet {
+    const fn ascii_set(self) -> AsciiSet {
+        match self {
+            Self::Fragment => FRAGMENT_SET,
+            Self::Query => QUERY_SET,
+            Self::SpecialQuery => SPECIAL_QUERY_SET,
+            Self::Path => PATH_SET,
+            Self::OpaquePath => C0_CONTROL_SET,
+            Self::Userinfo => USERINFO_SET,
+        }
+    }
+}
+
+/// Percent-encode a string with one of the URL encode sets
+///
+/// Useful for strings outside URLs that need the same escaping, such as
+/// filenames in headers. `%` itself is never encoded.
+///
+/// # Examples
+///
+/// ```
+/// use ars::{EncodeSet, encode_with_set};
+///
+/// assert_eq!(encode_with_set("a b?c", EncodeSet::Query), "a%20b?c");
+/// assert_eq!(encode_with_set("a b?c", EncodeSet::Path), "a%20b%3Fc");
+/// ```
+pub fn encode_with_set(input: &str, set: EncodeSet) -> String {
+    percent_encode_with_set(input, set.ascii_set())
+}
+
 /// Percent-encode fragment directly into buffer
 pub fn percent_encode_fragment_into(buffer: &mut String, input: &str) {
     percent_encode_into(buffer, input, FRAGMENT_SET);
@@ -150,8 +208,90 @@ pub fn percent_encode_userinfo_into(buffer: &mut String, input: &str) {
     percent_encode_into(buffer, input, USERINFO_SET);
 }
 
+/// Uppercase the hex digits of every valid `%xy` escape, borrowing when
+/// there is nothing to change
+/// Invalid escapes and all other characters are kept as-is
+pub fn uppercase_percent_escapes(input: &str) -> Cow<'_, str> {
+    let bytes = input.as_bytes();
+    let is_lower_escape = |index: usize| {
+        bytes[index] == b'%'
+            && index + 2 < bytes.len()
+            && hex_value(bytes[index + 1]).is_some()
+            && hex_value(bytes[index + 2]).is_some()
+            && (bytes[index + 1].is_ascii_lowercase() || bytes[index + 2].is_ascii_lowercase())
+    };
+    if !(0..bytes.len()).any(is_lower_escape) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut last = 0;
+    for index in (0..bytes.len()).filter(|&index| is_lower_escape(index)) {
+        output.push_str(&input[last..index]);
+        output.push('%');
+        output.push(char::from(bytes[index + 1].to_ascii_uppercase()));
+        output.push(char::from(bytes[index + 2].to_ascii_uppercase()));
+        last = index + 3;
+    }
+    output.push_str(&input[last..]);
+    Cow::Owned(output)
+}
+
 /// Decode percent-encoded string
+/// Invalid escapes are kept as-is
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidEncoding` if the decoded bytes are not valid UTF-8
 pub fn percent_decode(input: &str) -> Result<String> {
+    String::from_utf8(percent_decode_bytes(input)).map_err(|_| ParseError::InvalidEncoding)
+}
+
+/// Decode percent-encoded string, borrowing when there is nothing to decode
+/// Only allocates if at least one valid `%XX` escape is present
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidEncoding` if the decoded bytes are not valid UTF-8
+///
+/// # Examples
+///
+/// ```
+/// use ars::percent_decode_cow;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(percent_decode_cow("a-b").unwrap(), Cow::Borrowed("a-b")));
+/// assert_eq!(percent_decode_cow("a%20b").unwrap(), "a b");
+/// ```
+pub fn percent_decode_cow(input: &str) -> Result<Cow<'_, str>> {
+    if !input.contains('%') {
+        return Ok(Cow::Borrowed(input));
+    }
+    let decoded = percent_decode_bytes(input);
+    if decoded.len() == input.len() {
+        // No escape was valid, so the bytes are unchanged
+        return Ok(Cow::Borrowed(input));
+    }
+    String::from_utf8(decoded)
+        .map(Cow::Owned)
+        .map_err(|_| ParseError::InvalidEncoding)
+}
+
+/// Decode percent-encoded string, borrowing when there is nothing to decode
+/// Invalid UTF-8 in the decoded bytes is replaced with U+FFFD
+pub fn percent_decode_lossy(input: &str) -> Cow<'_, str> {
+    if !input.contains('%') {
+        return Cow::Borrowed(input);
+    }
+    match String::from_utf8(percent_decode_bytes(input)) {
+        Ok(decoded) => Cow::Owned(decoded),
+        Err(err) => Cow::Owned(String::from_utf8_lossy(err.as_bytes()).into_owned()),
+    }
+}
+
+/// Decode percent-encoded string into raw bytes
+/// Invalid escapes are kept as-is
+pub fn percent_decode_bytes(input: &str) -> Vec<u8> {
     let bytes = input.as_bytes();
     let mut output = Vec::with_capacity(bytes.len());
     let mut index = 0;
@@ -183,7 +323,7 @@ pub fn percent_decode(input: &str) -> Result<String> {
         }
     }
 
-    String::from_utf8(output).map_err(|_| ParseError::InvalidPercentEncoding)
+    output
 }
 
 const fn hex_digit(nibble: u8) -> char {
@@ -213,10 +353,77 @@ mod tests {
         assert_eq!(percent_decode("test").unwrap(), "test");
         assert_eq!(percent_decode("%2F").unwrap(), "/");
         assert_eq!(percent_decode("%C3%A9").unwrap(), "é");
+        assert_eq!(
+            percent_decode("%FF%FE").unwrap_err(),
+            ParseError::InvalidEncoding
+        );
     }
 
     #[test]
     fn test_percent_encode_non_ascii_as_utf8_bytes() {
         assert_eq!(percent_encode_with_set("café", PATH_SET), "caf%C3%A9");
     }
+
+    #[test]
+    fn test_encode_with_set() {
+        let input = "a b\"'#?/:@^`{|é";
+        let encode = |set| encode_with_set(input, set);
+        assert_eq!(encode(EncodeSet::Fragment), "a%20b%22'#?/:@^%60{|%C3%A9");
+        assert_eq!(encode(EncodeSet::Query), "a%20b%22'%23?/:@^`{|%C3%A9");
+        assert_eq!(
+            encode(EncodeSet::SpecialQuery),
+            "a%20b%22%27%23?/:@^`{|%C3%A9"
+        );
+        assert_eq!(
+            encode(EncodeSet::Path),
+            "a%20b%22'%23%3F/:@%5E%60%7B|%C3%A9"
+        );
+        assert_eq!(encode(EncodeSet::OpaquePath), "a b\"'#?/:@^`{|%C3%A9");
+        assert_eq!(
+            encode_with_set("x\u{1}\u{7f}", EncodeSet::OpaquePath),
+            "x%01%7F"
+        );
+        assert_eq!(
+            encode(EncodeSet::Userinfo),
+            "a%20b%22'%23%3F%2F%3A%40%5E%60%7B%7C%C3%A9"
+        );
+    }
+
+    #[test]
+    fn test_uppercase_percent_escapes() {
+        assert!(matches!(
+            uppercase_percent_escapes("a%2Fb%zz%"),
+            Cow::Borrowed("a%2Fb%zz%")
+        ));
+        assert_eq!(
+            uppercase_percent_escapes("a%2fb%e2%82%ac"),
+            "a%2Fb%E2%82%AC"
+        );
+        assert_eq!(uppercase_percent_escapes("%%2f%g1%a"), "%%2F%g1%a");
+    }
+
+    #[test]
+    fn test_percent_decode_cow() {
+        assert!(matches!(
+            percent_decode_cow("hello").unwrap(),
+            Cow::Borrowed("hello")
+        ));
+        assert!(matches!(
+            percent_decode_cow("100%").unwrap(),
+            Cow::Borrowed("100%")
+        ));
+        assert!(matches!(
+            percent_decode_cow("%zz").unwrap(),
+            Cow::Borrowed("%zz")
+        ));
+
+        let decoded = percent_decode_cow("a%20b").unwrap();
+        assert!(matches!(decoded, Cow::Owned(_)));
+        assert_eq!(decoded, "a b");
+
+        assert_eq!(
+            percent_decode_cow("%FF").unwrap_err(),
+            ParseError::InvalidEncoding
+        );
+    }
 }
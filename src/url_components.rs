@@ -22,6 +22,9 @@ pub struct UrlComponents {
     pub pathname_start: u32,
     pub search_start: u32,
     pub hash_start: u32,
+    /// Set by the parser when an IPv4 host was written with hex or
+    /// zero-prefixed octal parts
+    pub legacy_ipv4: bool,
 }
 
 impl UrlComponents {
@@ -0,0 +1,28 @@
+use core::ops::Range;
+
+/// Byte ranges of every URL component within `href()`
+///
+/// Produced by `Url::component_ranges`. Ranges exclude the delimiters
+/// (`:`, `//`, `@`, `?`, `#`), so `&href[ranges.query]` is "a=1" for
+/// `https://example.com/?a=1`. A missing component gets an empty range
+/// at the position it would take, so the ranges stay in href order.
+///
+/// - `scheme`: "https" (without the trailing ':')
+/// - `authority`: "user:pass@example.com:8080" (without the leading "//")
+/// - `userinfo`: "user:pass"
+/// - `host`: "example.com", with brackets for IPv6
+/// - `port`: "8080", empty when it is the default
+/// - `path`: "/path"
+/// - `query`: "query" (without '?')
+/// - `fragment`: "hash" (without '#')
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UrlRanges {
+    pub scheme: Range<usize>,
+    pub authority: Range<usize>,
+    pub userinfo: Range<usize>,
+    pub host: Range<usize>,
+    pub port: Range<usize>,
+    pub path: Range<usize>,
+    pub query: Range<usize>,
+    pub fragment: Range<usize>,
+}
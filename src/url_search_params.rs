@@ -1,4 +1,5 @@
 use crate::compat::{String, ToString, Vec};
+use crate::error::{ParseError, Result};
 
 /// Represents URL search parameters (query string).
 /// Provides methods to parse, manipulate, and serialize query parameters.
@@ -13,22 +14,49 @@ impl UrlSearchParams {
     }
 
     /// Parse from a query string (with or without leading `?`)
+    /// Invalid UTF-8 after percent-decoding is replaced with U+FFFD.
     pub fn parse(query: &str) -> Self {
-        let query = query.strip_prefix('?').unwrap_or(query);
+        let params = split_pairs(query)
+            .map(|(key, value)| (decode_component(key), decode_component(value)))
+            .collect();
 
-        if query.is_empty() {
-            return Self::new();
-        }
+        Self { params }
+    }
 
-        let params = query
-            .split('&')
-            .filter(|pair| !pair.is_empty())
-            .map(|pair| match pair.split_once('=') {
-                Some((key, value)) => (decode_component(key), decode_component(value)),
-                None => (decode_component(pair), String::new()),
+    /// Parse from a query string, rejecting invalid UTF-8.
+    ///
+    /// Same as `parse`, except that a key or value whose percent-decoded
+    /// bytes are not valid UTF-8 is an error instead of being replaced
+    /// with U+FFFD.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::InvalidEncoding` if any decoded key or value is not valid UTF-8.
+    pub fn parse_strict(query: &str) -> Result<Self> {
+        let params = split_pairs(query)
+            .map(|(key, value)| {
+                Ok((
+                    decode_component_strict(key)?,
+                    decode_component_strict(value)?,
+                ))
             })
-            .collect();
+            .collect::<Result<_>>()?;
+
+        Ok(Self { params })
+    }
 
+    /// Build from key/value pairs that are already form-urlencoded.
+    ///
+    /// Each key and value is decoded the same way `parse()` decodes a query,
+    /// so `from_pairs_encoded(&[("a", "%26")])` stores the value `&`.
+    /// Use `append()` instead for raw values: it stores them as given and
+    /// encodes them on serialization, so `append("a", "%26")` serializes
+    /// as `a=%2526`.
+    pub fn from_pairs_encoded(pairs: &[(&str, &str)]) -> Self {
+        let params = pairs
+            .iter()
+            .map(|(key, value)| (decode_component(key), decode_component(value)))
+            .collect();
         Self { params }
     }
 
@@ -51,6 +79,17 @@ impl UrlSearchParams {
         }
     }
 
+    /// Delete only the first pair with the given key.
+    /// If `value` is provided, the pair must match both key and value.
+    /// Returns whether a pair was removed.
+    pub fn remove_first(&mut self, key: &str, value: Option<&str>) -> bool {
+        let position = self
+            .params
+            .iter()
+            .position(|(k, v)| k == key && value.is_none_or(|val| v == val));
+        position.map(|index| self.params.remove(index)).is_some()
+    }
+
     /// Get the first value for a key.
     pub fn get(&self, key: &str) -> Option<&str> {
         self.params
@@ -59,7 +98,10 @@ impl UrlSearchParams {
             .map(|(_, v)| v.as_str())
     }
 
-    /// Get all values for a key.
+    /// Get all values for a key, in insertion order.
+    ///
+    /// Pairs keep the order they were parsed or appended in, and `sort()`
+    /// is stable, so values sharing a key are never reordered.
     pub fn get_all(&self, key: &str) -> Vec<&str> {
         self.params
             .iter()
@@ -68,6 +110,15 @@ impl UrlSearchParams {
             .collect()
     }
 
+    /// Get owned copies of all values for a key, in insertion order.
+    pub fn get_all_owned(&self, key: &str) -> Vec<String> {
+        self.params
+            .iter()
+            .filter(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+            .collect()
+    }
+
     /// Check if a key exists.
     /// If `value` is provided, checks for a specific key-value pair.
     /// Otherwise, checks if the key exists at all.
@@ -103,8 +154,60 @@ impl UrlSearchParams {
     }
 
     /// Sort parameters by key.
+    ///
+    /// Keys are compared by UTF-16 code units, as the WHATWG spec and
+    /// browsers do, so characters above U+FFFF sort before U+E000..=U+FFFF.
+    /// The sort is stable: pairs sharing a key keep their relative order.
     pub fn sort(&mut self) {
-        self.params.sort_by(|a, b| a.0.cmp(&b.0));
+        self.params.sort_by(|a, b| cmp_utf16(&a.0, &b.0));
+    }
+
+    /// Sort parameters by key, then by value.
+    ///
+    /// Unlike the spec `sort()`, which only orders by key and keeps the
+    /// relative order of pairs sharing a key, this also orders those pairs
+    /// by value so the output is deterministic regardless of insertion order.
+    pub fn sort_stable_with_values(&mut self) {
+        self.params
+            .sort_by(|a, b| cmp_utf16(&a.0, &b.0).then_with(|| cmp_utf16(&a.1, &b.1)));
+    }
+
+    /// Get the pairs sorted by key, without reordering `self`.
+    ///
+    /// Uses the same stable ordering as `sort()`, which helps when building
+    /// a canonical query string while keeping the original order intact.
+    pub fn entries_sorted(&self) -> Vec<(&str, &str)> {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_by(|a, b| cmp_utf16(a.0, b.0));
+        entries
+    }
+
+    /// Build a canonical query string, without the leading `?`.
+    ///
+    /// Pairs are sorted by key, then by value, comparing the decoded text
+    /// by UTF-16 code units like `sort()`. Keys and values are then encoded
+    /// with the RFC 3986 unreserved set: ASCII alphanumerics and `-`, `.`,
+    /// `_`, `~` are kept, every other byte becomes an uppercase `%XX`
+    /// escape (so a space is always `%20`, never `+`). Every pair is
+    /// written as `key=value`, even with an empty value.
+    ///
+    /// Equivalent queries, e.g. `b=2&a=1` and `a=1&b=2` or `q=a+b` and
+    /// `q=a%20b`, produce the same output, which suits cache keys and
+    /// request signing.
+    pub fn canonical_query(&self) -> String {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_by(|a, b| cmp_utf16(a.0, b.0).then_with(|| cmp_utf16(a.1, b.1)));
+
+        let mut result = String::new();
+        for (i, (key, value)) in entries.into_iter().enumerate() {
+            if i > 0 {
+                result.push('&');
+            }
+            encode_unreserved(key, &mut result);
+            result.push('=');
+            encode_unreserved(value, &mut result);
+        }
+        result
     }
 
     /// Get the number of parameters (WHATWG API).
@@ -175,14 +278,36 @@ impl core::fmt::Display for UrlSearchParams {
     }
 }
 
+/// Compare strings by UTF-16 code units, as JavaScript does.
+/// Only differs from `str` ordering when a character above U+FFFF meets
+/// one in U+E000..=U+FFFF; ASCII input takes the byte comparison.
+fn cmp_utf16(a: &str, b: &str) -> core::cmp::Ordering {
+    if a.is_ascii() && b.is_ascii() {
+        return a.cmp(b);
+    }
+    a.encode_utf16().cmp(b.encode_utf16())
+}
+
+/// Split a query string (with or without leading `?`) into raw key/value pairs.
+pub(crate) fn split_pairs(query: &str) -> impl Iterator<Item = (&str, &str)> {
+    let query = query.strip_prefix('?').unwrap_or(query);
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+}
+
 /// Encode a component for use in query strings.
-fn encode_component(s: &str) -> String {
+/// Uses the `application/x-www-form-urlencoded` percent-encode set:
+/// only ASCII alphanumerics and `*`, `-`, `.`, `_` are left as-is,
+/// space becomes `+`.
+pub(crate) fn encode_component(s: &str) -> String {
     use core::fmt::Write;
 
     let mut result = String::with_capacity(s.len());
     for byte in s.bytes() {
         match byte {
-            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'*' | b'-' | b'.' | b'_' => {
                 result.push(byte as char);
             }
             b' ' => result.push('+'),
@@ -194,8 +319,34 @@ fn encode_component(s: &str) -> String {
     result
 }
 
+/// Encode a component keeping only the RFC 3986 unreserved characters,
+/// appending to `out`.
+fn encode_unreserved(s: &str, out: &mut String) {
+    use core::fmt::Write;
+
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            let _ = write!(out, "%{byte:02X}");
+        }
+    }
+}
+
 /// Decode a component from a query string.
+/// Invalid UTF-8 is replaced with U+FFFD (WHATWG behavior).
 fn decode_component(s: &str) -> String {
+    String::from_utf8_lossy(&decode_component_bytes(s)).into_owned()
+}
+
+/// Decode a component from a query string, rejecting invalid UTF-8.
+fn decode_component_strict(s: &str) -> Result<String> {
+    String::from_utf8(decode_component_bytes(s)).map_err(|_| ParseError::InvalidEncoding)
+}
+
+/// Decode `+` and `%XX` escapes into raw bytes.
+/// Malformed escapes are kept as-is.
+fn decode_component_bytes(s: &str) -> Vec<u8> {
     let mut result = Vec::with_capacity(s.len());
     let bytes = s.as_bytes();
     let mut i = 0;
@@ -203,10 +354,12 @@ fn decode_component(s: &str) -> String {
     while i < bytes.len() {
         match bytes[i] {
             b'+' => result.push(b' '),
+            // Both hex digits must be in bounds: i + 2 <= len - 1
             b'%' if i + 2 < bytes.len() => {
-                let hex = &s[i + 1..i + 3];
-                if let Ok(byte) = u8::from_str_radix(hex, 16) {
-                    result.push(byte);
+                let high = (bytes[i + 1] as char).to_digit(16);
+                let low = (bytes[i + 2] as char).to_digit(16);
+                if let (Some(high), Some(low)) = (high, low) {
+                    result.push((high * 16 + low) as u8);
                     i += 2; // Extra increment for hex digits
                 } else {
                     result.push(b'%');
@@ -217,7 +370,7 @@ fn decode_component(s: &str) -> String {
         i += 1;
     }
 
-    String::from_utf8_lossy(&result).into_owned()
+    result
 }
 
 impl From<&str> for UrlSearchParams {
@@ -232,8 +385,33 @@ impl From<String> for UrlSearchParams {
     }
 }
 
+impl FromIterator<(String, String)> for UrlSearchParams {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self {
+            params: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a> FromIterator<(&'a str, &'a str)> for UrlSearchParams {
+    fn from_iter<I: IntoIterator<Item = (&'a str, &'a str)>>(iter: I) -> Self {
+        Self {
+            params: iter
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl Extend<(String, String)> for UrlSearchParams {
+    fn extend<I: IntoIterator<Item = (String, String)>>(&mut self, iter: I) {
+        self.params.extend(iter);
+    }
+}
+
 #[cfg(test)]
-#[allow(clippy::single_char_pattern)]
+#[allow(clippy::unwrap_used, clippy::single_char_pattern)]
 mod tests {
     use super::*;
 
@@ -286,6 +464,45 @@ mod tests {
         assert_eq!(all, vec!["value1", "value2"]);
     }
 
+    #[test]
+    fn test_from_iter() {
+        let pairs = vec![("a", "1"), ("b", "2"), ("a", "3")];
+        let params: UrlSearchParams = pairs.into_iter().collect();
+        assert_eq!(params.size(), 3);
+        let keys: Vec<&str> = params.keys().collect();
+        assert_eq!(keys, vec!["a", "b", "a"]);
+        assert_eq!(params.get_all("a"), vec!["1", "3"]);
+        assert_eq!(params.serialize(), "?a=1&b=2&a=3");
+    }
+
+    #[test]
+    fn test_from_iter_owned_and_extend() {
+        let mut params: UrlSearchParams = vec![("a".to_string(), "1".to_string())]
+            .into_iter()
+            .collect();
+        params.extend(vec![
+            ("b".to_string(), "2".to_string()),
+            ("a".to_string(), "3".to_string()),
+        ]);
+        assert_eq!(params.size(), 3);
+        assert_eq!(params.get_all("a"), vec!["1", "3"]);
+        assert_eq!(params.get("b"), Some("2"));
+    }
+
+    #[test]
+    fn test_from_pairs_encoded() {
+        let params = UrlSearchParams::from_pairs_encoded(&[("a", "%26"), ("b+c", "d+e")]);
+        assert_eq!(params.get("a"), Some("&"));
+        assert_eq!(params.get("b c"), Some("d e"));
+        assert_eq!(params.to_string(), "a=%26&b+c=d+e");
+
+        // append() stores the raw value, so the '%' itself gets encoded
+        let mut params = UrlSearchParams::new();
+        params.append("a", "%26");
+        assert_eq!(params.get("a"), Some("%26"));
+        assert_eq!(params.to_string(), "a=%2526");
+    }
+
     #[test]
     fn test_append() {
         let mut params = UrlSearchParams::new();
@@ -337,6 +554,32 @@ mod tests {
         assert_eq!(keys, vec!["a", "b", "c"]);
     }
 
+    #[test]
+    fn test_sort_stable_with_values() {
+        let mut params = UrlSearchParams::parse("z=2&a=2&a=1");
+        params.sort();
+        assert_eq!(params.to_string(), "a=2&a=1&z=2");
+
+        let mut params = UrlSearchParams::parse("z=2&a=2&a=1");
+        params.sort_stable_with_values();
+        assert_eq!(params.to_string(), "a=1&a=2&z=2");
+
+        // Same UTF-16 order as sort(): U+1F600 comes before U+E000
+        let mut params = UrlSearchParams::new();
+        params.append("\u{E000}", "1");
+        params.append("\u{1F600}", "2");
+        params.append("a", "\u{E000}");
+        params.append("a", "\u{1F600}");
+        let mut by_key = params.clone();
+        by_key.sort();
+        params.sort_stable_with_values();
+        let keys: Vec<_> = params.iter().map(|(key, _)| key).collect();
+        let sorted_keys: Vec<_> = by_key.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, sorted_keys);
+        assert_eq!(keys, ["a", "a", "\u{1F600}", "\u{E000}"]);
+        assert_eq!(params.get("a"), Some("\u{1F600}"));
+    }
+
     #[test]
     fn test_to_string() {
         let params = UrlSearchParams::parse("key1=value1&key2=value2");
@@ -356,6 +599,48 @@ mod tests {
         assert!(params.serialize().contains("value+with+spaces"));
     }
 
+    #[test]
+    fn test_form_urlencoded_set() {
+        // Expected values match URLSearchParams.toString() in browsers
+        let mut params = UrlSearchParams::new();
+        params.append("tilde", "a~b");
+        params.append("star", "a*b-c._d");
+        params.append("utf8", "é€😀");
+        params.append("misc", "!'()");
+        assert_eq!(
+            params.to_string(),
+            "tilde=a%7Eb&star=a*b-c._d&utf8=%C3%A9%E2%82%AC%F0%9F%98%80&misc=%21%27%28%29"
+        );
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let params = UrlSearchParams::parse_strict("a=%C3%A9&b=x+y").unwrap();
+        assert_eq!(params.get("a"), Some("é"));
+        assert_eq!(params.get("b"), Some("x y"));
+
+        assert_eq!(
+            UrlSearchParams::parse_strict("a=%FF%FE").unwrap_err(),
+            ParseError::InvalidEncoding
+        );
+        assert_eq!(
+            UrlSearchParams::parse_strict("%FF=1").unwrap_err(),
+            ParseError::InvalidEncoding
+        );
+
+        // The default parser stays lossy
+        let params = UrlSearchParams::parse("a=%FF%FE");
+        assert_eq!(params.get("a"), Some("\u{FFFD}\u{FFFD}"));
+    }
+
+    #[test]
+    fn test_decode_malformed_escapes() {
+        let params = UrlSearchParams::parse("a=%+1&b=%aé&c=%4");
+        assert_eq!(params.get("a"), Some("% 1"));
+        assert_eq!(params.get("b"), Some("%aé"));
+        assert_eq!(params.get("c"), Some("%4"));
+    }
+
     #[test]
     fn test_decoding() {
         let params = UrlSearchParams::parse("key=value+with+spaces");
@@ -567,4 +852,27 @@ mod tests {
         let params = UrlSearchParams::parse("key=value=with=equals");
         assert_eq!(params.get("key"), Some("value=with=equals"));
     }
+
+    #[test]
+    fn test_decode_trailing_escape() {
+        let params = UrlSearchParams::parse("key=val%20");
+        assert_eq!(params.get("key"), Some("val "));
+
+        let params = UrlSearchParams::parse("key=%41");
+        assert_eq!(params.get("key"), Some("A"));
+
+        let params = UrlSearchParams::parse("a=1&key=%41");
+        assert_eq!(params.get("key"), Some("A"));
+
+        // Incomplete escapes stay literal
+        let params = UrlSearchParams::parse("key=%2");
+        assert_eq!(params.get("key"), Some("%2"));
+        let params = UrlSearchParams::parse("key=%");
+        assert_eq!(params.get("key"), Some("%"));
+        let params = UrlSearchParams::parse("%2=x");
+        assert_eq!(params.get("%2"), Some("x"));
+
+        assert_eq!(decode_component_bytes("%41"), b"A");
+        assert_eq!(decode_component_bytes("a%4"), b"a%4");
+    }
 }
@@ -408,7 +408,7 @@ fn test_nodejs_special_schemes() {
 
 #[test]
 fn test_set_host_return_false_for_non_special() {
-    // set_host should return false for non-special schemes
+    // set_host should return false for non-special URLs with an opaque path
     let mut url = parse("mailto:user@example.com", None).unwrap();
     assert!(!url.set_host("newhost.com"));
 }
@@ -471,6 +471,25 @@ fn test_set_valid_port() {
     assert_eq!(url.port(), "");
 }
 
+#[test]
+fn test_default_port_only_stripped_for_special_schemes() {
+    // Non-special schemes have no default port, so ":80" is kept
+    for input in ["foo://host:80/", "foo://[::1]:80/", "foo://host:443/x"] {
+        let url = parse(input, None).unwrap();
+        assert_eq!(url.href(), input);
+        assert!(!url.port().is_empty());
+    }
+
+    for (input, expected) in [
+        ("http://host:80/", "http://host/"),
+        ("https://host:443/", "https://host/"),
+        ("ws://[::1]:80/", "ws://[::1]/"),
+        ("http://host:443/", "http://host:443/"),
+    ] {
+        assert_eq!(parse(input, None).unwrap().href(), expected);
+    }
+}
+
 // ============================================================================
 // URL Component Edge Cases
 // ============================================================================
@@ -558,6 +577,32 @@ fn test_empty_search_and_hash() {
     assert_eq!(url.hash(), "");
 }
 
+#[test]
+fn test_empty_search_and_hash_href_round_trip() {
+    for input in [
+        "http://x/?#",
+        "http://x/?",
+        "http://x/#",
+        "http://example.com/path?#",
+        "foo://h/?#",
+        "foo:bar?#",
+        "file:///?#",
+    ] {
+        let url = parse(input, None).unwrap();
+        assert_eq!(url.href(), input);
+        assert_eq!(url.search(), "");
+        assert_eq!(url.hash(), "");
+    }
+
+    // Missing path is added, delimiters kept
+    let url = parse("http://x?#", None).unwrap();
+    assert_eq!(url.href(), "http://x/?#");
+
+    // Relative references keep them too
+    let url = parse("?#", Some("http://x/path?q#h")).unwrap();
+    assert_eq!(url.href(), "http://x/path?#");
+}
+
 #[test]
 fn test_tab_and_newline_stripping() {
     // Tabs and newlines should be stripped
@@ -594,16 +639,60 @@ fn test_origin_special_schemes() {
 fn test_origin_file_scheme() {
     // file: URLs have null origin
     let url = parse("file:///path/to/file", None).unwrap();
-    let origin = url.origin();
-    assert!(origin == "null" || origin == "file://");
+    assert_eq!(url.origin(), "null");
+
+    let url = parse("file://host/share", None).unwrap();
+    assert_eq!(url.origin(), "null");
 }
 
 #[test]
 fn test_origin_non_special() {
     // Non-special schemes have opaque origin
     let url = parse("mailto:user@example.com", None).unwrap();
-    let origin = url.origin();
-    assert!(origin == "null" || origin.is_empty());
+    assert_eq!(url.origin(), "null");
+
+    let url = parse("foo://example.com:8080/path", None).unwrap();
+    assert_eq!(url.origin(), "null");
+}
+
+#[test]
+fn test_origin_default_port() {
+    let url = parse("https://example.com:443/", None).unwrap();
+    assert_eq!(url.origin(), "https://example.com");
+
+    let url = parse("ws://example.com:443/", None).unwrap();
+    assert_eq!(url.origin(), "ws://example.com:443");
+
+    let url = parse("ftp://example.com:21/", None).unwrap();
+    assert_eq!(url.origin(), "ftp://example.com");
+}
+
+#[test]
+fn test_origin_idn_host() {
+    let url = parse("http://münchen.de/path", None).unwrap();
+    assert_eq!(url.origin(), "http://xn--mnchen-3ya.de");
+
+    let url = parse("https://XN--MNCHEN-3YA.de:8443/", None).unwrap();
+    assert_eq!(url.origin(), "https://xn--mnchen-3ya.de:8443");
+}
+
+#[test]
+fn test_origin_blob() {
+    let url = parse("blob:https://example.com:443/uuid", None).unwrap();
+    assert_eq!(url.origin(), "https://example.com");
+
+    let url = parse("blob:http://example.org:88/uuid?q#h", None).unwrap();
+    assert_eq!(url.origin(), "http://example.org:88");
+
+    // Only http(s) inner URLs give a tuple origin
+    for input in [
+        "blob:d3958f5c-0777-0845-9dcf-2cb28783acaf",
+        "blob:ftp://host/path",
+        "blob:file:///tmp/x",
+        "blob:blob:https://example.org/",
+    ] {
+        assert_eq!(parse(input, None).unwrap().origin(), "null", "{input}");
+    }
 }
 
 // ============================================================================
@@ -57,8 +57,31 @@ fn test_set_password() {
 fn test_set_password_without_username() {
     let mut url = parse("https://example.com/", None).unwrap();
 
-    // Can't set password without username
-    assert!(!url.set_password("pass"));
+    // A password alone is serialized with an empty username
+    assert!(url.set_password("pass"));
+    assert_eq!(url.href(), "https://:pass@example.com/");
+}
+
+#[test]
+fn test_set_credentials_ignored_without_host() {
+    // Setters that are no-ops per spec must not touch the URL at all
+    for input in [
+        "file:///tmp/x",
+        "file://host/x",
+        "mailto:user@example.com",
+        "foo:/path",
+        "foo:///path?q#h",
+    ] {
+        let mut url = parse(input, None).unwrap();
+        let before = format!("{url:?}");
+
+        assert!(!url.set_username("user"), "{input}");
+        assert!(!url.set_password("pass"), "{input}");
+        assert!(!url.set_username(""), "{input}");
+        assert!(!url.set_password(""), "{input}");
+        assert_eq!(url.href(), input);
+        assert_eq!(format!("{url:?}"), before);
+    }
 }
 
 #[test]
@@ -166,3 +189,21 @@ fn test_set_hash_with_existing_search() {
     url.set_hash("hash");
     assert_eq!(url.href(), "https://example.com/?query#hash");
 }
+
+#[test]
+fn test_setter_results_round_trip() {
+    let mut url = parse("sc:/x", None).unwrap();
+    url.set_pathname("//p");
+    assert_eq!(url.href(), "sc:/.//p");
+    assert_eq!(parse(url.href(), None).unwrap(), url);
+
+    let mut url = parse("http://example.com:8080/", None).unwrap();
+    url.set_protocol("https");
+    url.set_port("443");
+    url.set_search("a b");
+    url.set_hash("c d");
+    let reparsed = parse(url.href(), None).unwrap();
+    assert_eq!(reparsed, url);
+    assert_eq!(reparsed.port(), url.port());
+    assert_eq!(reparsed.search(), url.search());
+}
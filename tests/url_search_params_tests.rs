@@ -382,3 +382,89 @@ fn test_delete_with_value() {
     assert!(values.contains(&"value3"));
     assert_eq!(params.get("other"), Some("data"));
 }
+
+#[test]
+fn test_get_all_insertion_order() {
+    let mut params = UrlSearchParams::parse("a=1&b&a=2&a=3");
+    assert_eq!(params.get_all("a"), ["1", "2", "3"]);
+
+    params.append("b", "x");
+    params.append("c", "y");
+    assert_eq!(params.get_all("a"), ["1", "2", "3"]);
+
+    // sort() only orders by key, keeping equal keys in place
+    params.append("a", "0");
+    params.sort();
+    assert_eq!(params.get_all("a"), ["1", "2", "3", "0"]);
+    assert_eq!(params.get_all("b"), ["", "x"]);
+
+    let owned: Vec<String> = params.get_all_owned("a");
+    assert_eq!(owned, ["1", "2", "3", "0"]);
+    assert!(params.get_all_owned("missing").is_empty());
+}
+
+#[test]
+fn test_remove_first() {
+    let mut params = UrlSearchParams::parse("a=1&a=2&a=1");
+    assert!(params.remove_first("a", Some("1")));
+    assert_eq!(params.to_string(), "a=2&a=1");
+
+    assert!(params.remove_first("a", None));
+    assert_eq!(params.to_string(), "a=1");
+
+    assert!(!params.remove_first("a", Some("2")));
+    assert!(!params.remove_first("b", None));
+    assert_eq!(params.to_string(), "a=1");
+
+    // delete() still removes every match
+    let mut params = UrlSearchParams::parse("a=1&a=2&a=1");
+    params.delete("a", Some("1"));
+    assert_eq!(params.to_string(), "a=2");
+}
+
+#[test]
+fn test_entries_sorted() {
+    let params = UrlSearchParams::parse("c=3&a=2&b=1&a=1");
+    assert_eq!(
+        params.entries_sorted(),
+        [("a", "2"), ("a", "1"), ("b", "1"), ("c", "3")]
+    );
+
+    // The stored order is untouched
+    let keys: Vec<&str> = params.keys().collect();
+    assert_eq!(keys, ["c", "a", "b", "a"]);
+    assert_eq!(params.to_string(), "c=3&a=2&b=1&a=1");
+}
+
+#[test]
+fn test_canonical_query() {
+    let params = UrlSearchParams::parse("b=2&a=1&a=0");
+    assert_eq!(params.canonical_query(), "a=0&a=1&b=2");
+
+    // Spaces encode as %20 however they were written, on every call
+    let params = UrlSearchParams::parse("q=a+b&r=a%20b&s=~*");
+    assert_eq!(params.canonical_query(), "q=a%20b&r=a%20b&s=~%2A");
+    assert_eq!(params.canonical_query(), params.canonical_query());
+
+    let params = UrlSearchParams::parse("flag&k=%C3%A9");
+    assert_eq!(params.canonical_query(), "flag=&k=%C3%A9");
+    assert_eq!(UrlSearchParams::new().canonical_query(), "");
+}
+
+#[test]
+fn test_sort_utf16_code_units() {
+    // U+1F600 is the surrogate pair D83D DE00, which sorts before U+FFFD
+    // by UTF-16 code units even though it is the larger scalar value
+    let mut params = UrlSearchParams::new();
+    params.append("\u{FFFD}", "1");
+    params.append("\u{1F600}", "2");
+    params.append("z", "3");
+    params.append("\u{E000}", "4");
+
+    params.sort();
+    let keys: Vec<&str> = params.keys().collect();
+    assert_eq!(keys, ["z", "\u{1F600}", "\u{E000}", "\u{FFFD}"]);
+
+    let sorted: Vec<&str> = params.entries_sorted().iter().map(|(k, _)| *k).collect();
+    assert_eq!(sorted, keys);
+}
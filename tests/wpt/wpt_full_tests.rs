@@ -383,3 +383,86 @@ fn test_full_wpt_suite() {
         "Expected at least 873 WPT tests, but found {total_tests}",
     );
 }
+
+#[test]
+fn test_wpt_can_parse_matches_parse() {
+    let test_data = include_str!("./urltestdata.json");
+    let tests: Vec<TestCase> =
+        serde_json::from_str(test_data).expect("Failed to parse WPT test data");
+
+    let mut mismatches = Vec::new();
+    for test in tests {
+        let TestCase::UrlTest {
+            input,
+            base,
+            failure,
+            ..
+        } = test
+        else {
+            continue;
+        };
+        let expected = !failure.unwrap_or(false);
+        let can_parse = Url::can_parse(&input, base.as_deref());
+        let parsed = Url::parse(&input, base.as_deref()).is_ok();
+        if can_parse != expected || can_parse != parsed {
+            mismatches.push((input, base, expected, can_parse, parsed));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "can_parse mismatches: {mismatches:#?}"
+    );
+}
+
+type Getter = fn(&Url) -> &str;
+
+/// Re-parse `url.href()` and describe any way the result differs from `url`
+fn round_trip_mismatch(url: &Url) -> Option<String> {
+    let reparsed = match Url::parse(url.href(), None) {
+        Ok(reparsed) => reparsed,
+        Err(err) => return Some(format!("re-parse failed: {err:?}")),
+    };
+    let getters: [(&str, Getter); 9] = [
+        ("href", Url::href),
+        ("protocol", Url::protocol),
+        ("username", Url::username),
+        ("password", Url::password),
+        ("hostname", Url::hostname),
+        ("port", Url::port),
+        ("pathname", Url::pathname),
+        ("search", Url::search),
+        ("hash", Url::hash),
+    ];
+    for (name, get) in getters {
+        if get(url) != get(&reparsed) {
+            return Some(format!("{name}: {:?} -> {:?}", get(url), get(&reparsed)));
+        }
+    }
+    (*url != reparsed).then(|| "urls not equal".to_string())
+}
+
+#[test]
+fn test_wpt_href_round_trip() {
+    let test_data = include_str!("./urltestdata.json");
+    let tests: Vec<TestCase> =
+        serde_json::from_str(test_data).expect("Failed to parse WPT test data");
+
+    let mut mismatches = Vec::new();
+    for test in tests {
+        let TestCase::UrlTest { input, base, .. } = test else {
+            continue;
+        };
+        let Ok(url) = Url::parse(&input, base.as_deref()) else {
+            continue;
+        };
+        if let Some(mismatch) = round_trip_mismatch(&url) {
+            mismatches.push((input, base, url.href().to_string(), mismatch));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "non-idempotent serializations: {mismatches:#?}"
+    );
+}